@@ -0,0 +1,293 @@
+/// Pluggable coolant property models for reactor thermal-hydraulics
+///
+/// Every property function in `fluid_dynamics`, `heat_transfer`, and `thermal_hydraulics`
+/// used to be hard-wired to liquid sodium. The `Coolant` trait below gives each fluid its
+/// own temperature-dependent correlations for density, viscosity, thermal conductivity, and
+/// specific heat, so `ReactorConfig`/`ReactorCalculator` can be parameterized by coolant
+/// type instead of assuming an SFR.
+use crate::constants::SODIUM_CP;
+use crate::fluid_properties::FluidProperties;
+use pyo3::prelude::*;
+
+/// Common coolant property interface, all correlations take temperature in Kelvin.
+pub trait Coolant {
+    /// Density in kg/m³
+    fn density(&self, temperature: f64) -> f64;
+    /// Dynamic viscosity in Pa·s
+    fn viscosity(&self, temperature: f64) -> f64;
+    /// Thermal conductivity in W/m·K
+    fn thermal_conductivity(&self, temperature: f64) -> f64;
+    /// Specific heat capacity in J/kg·K
+    fn cp(&self, temperature: f64) -> f64;
+
+    /// Whether this coolant is a liquid metal (Pr ≪ 1). Liquid metals need a Péclet-based
+    /// Nusselt correlation rather than Dittus-Boelter, which assumes Pr ≈ O(1).
+    fn is_liquid_metal(&self) -> bool {
+        false
+    }
+}
+
+/// Liquid sodium, the default SFR primary coolant.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sodium;
+
+impl Coolant for Sodium {
+    fn density(&self, temperature: f64) -> f64 {
+        crate::fluid_dynamics::sodium_density(temperature)
+    }
+
+    fn viscosity(&self, temperature: f64) -> f64 {
+        crate::fluid_dynamics::sodium_viscosity(temperature)
+    }
+
+    fn thermal_conductivity(&self, temperature: f64) -> f64 {
+        crate::heat_transfer::sodium_thermal_conductivity(temperature)
+    }
+
+    fn cp(&self, _temperature: f64) -> f64 {
+        SODIUM_CP
+    }
+
+    fn is_liquid_metal(&self) -> bool {
+        true
+    }
+}
+
+/// Liquid lead, used in some lead-cooled fast reactor (LFR) designs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Lead;
+
+impl Coolant for Lead {
+    fn density(&self, temperature: f64) -> f64 {
+        // ρ = 11367 - 1.1944 * T kg/m³ (Sobolev correlation)
+        11367.0 - 1.1944 * temperature
+    }
+
+    fn viscosity(&self, temperature: f64) -> f64 {
+        // μ = 4.55e-4 * exp(1069 / T) Pa·s
+        4.55e-4 * (1069.0 / temperature).exp()
+    }
+
+    fn thermal_conductivity(&self, temperature: f64) -> f64 {
+        // k = 9.2 + 0.011 * T W/m·K
+        9.2 + 0.011 * temperature
+    }
+
+    fn cp(&self, _temperature: f64) -> f64 {
+        147.3 // J/kg·K
+    }
+
+    fn is_liquid_metal(&self) -> bool {
+        true
+    }
+}
+
+/// Lead-bismuth eutectic (LBE), used in some lead-cooled fast reactor designs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Lbe;
+
+impl Coolant for Lbe {
+    fn density(&self, temperature: f64) -> f64 {
+        // ρ = 11096 - 1.3236 * T kg/m³
+        11096.0 - 1.3236 * temperature
+    }
+
+    fn viscosity(&self, temperature: f64) -> f64 {
+        // μ = 4.94e-4 * exp(754.1 / T) Pa·s
+        4.94e-4 * (754.1 / temperature).exp()
+    }
+
+    fn thermal_conductivity(&self, temperature: f64) -> f64 {
+        // k = 3.284 + 0.01617 * T - 2.305e-6 * T² W/m·K
+        3.284 + 0.01617 * temperature - 2.305e-6 * temperature * temperature
+    }
+
+    fn cp(&self, _temperature: f64) -> f64 {
+        159.0 // J/kg·K
+    }
+
+    fn is_liquid_metal(&self) -> bool {
+        true
+    }
+}
+
+/// Light water, for PWR/BWR-style loops.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Water;
+
+impl Coolant for Water {
+    fn density(&self, temperature: f64) -> f64 {
+        let t = temperature - 273.15;
+        1000.0 - 0.0178 * (t - 4.0).abs().powf(1.7)
+    }
+
+    fn viscosity(&self, temperature: f64) -> f64 {
+        let t = temperature - 273.15;
+        2.414e-5 * 10f64.powf(247.8 / (t + 133.15))
+    }
+
+    fn thermal_conductivity(&self, temperature: f64) -> f64 {
+        let t = temperature - 273.15;
+        0.5706 + 0.0017624 * t - 7.81e-6 * t * t
+    }
+
+    fn cp(&self, _temperature: f64) -> f64 {
+        4186.0 // J/kg·K
+    }
+}
+
+/// Helium gas, for gas-cooled fast/high-temperature reactor designs.
+///
+/// Gas density depends on pressure as well as temperature, so the operating pressure is
+/// carried on the struct rather than assumed.
+#[derive(Debug, Clone, Copy)]
+pub struct Helium {
+    /// System pressure in Pascals.
+    pub pressure: f64,
+}
+
+impl Coolant for Helium {
+    fn density(&self, temperature: f64) -> f64 {
+        const R_SPECIFIC: f64 = 2077.0; // J/kg·K, specific gas constant for helium
+        self.pressure / (R_SPECIFIC * temperature)
+    }
+
+    fn viscosity(&self, temperature: f64) -> f64 {
+        3.674e-7 * temperature.powf(0.7)
+    }
+
+    fn thermal_conductivity(&self, temperature: f64) -> f64 {
+        2.682e-3 * (1.0 + 1.123e-3 * temperature)
+    }
+
+    fn cp(&self, _temperature: f64) -> f64 {
+        5193.0 // J/kg·K
+    }
+}
+
+/// Generic molten fluoride salt (FLiBe-like), for molten-salt reactor designs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MoltenSalt;
+
+impl Coolant for MoltenSalt {
+    fn density(&self, temperature: f64) -> f64 {
+        2413.0 - 0.488 * temperature
+    }
+
+    fn viscosity(&self, temperature: f64) -> f64 {
+        1.16e-4 * (3755.0 / temperature).exp()
+    }
+
+    fn thermal_conductivity(&self, _temperature: f64) -> f64 {
+        1.0 // W/m·K, roughly constant over the operating range
+    }
+
+    fn cp(&self, _temperature: f64) -> f64 {
+        2386.0 // J/kg·K
+    }
+}
+
+/// Which coolant a `ReactorConfig` uses. Kept as a plain enum (rather than a trait object)
+/// so it stays `Copy`, serializable, and usable from Python.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[pyclass]
+pub enum CoolantKind {
+    Sodium,
+    Lead,
+    Lbe,
+    Water,
+    Helium,
+    MoltenSalt,
+}
+
+impl CoolantKind {
+    /// Build the concrete property model for this coolant. `pressure` (Pascals) is only
+    /// used by gas coolants, whose density depends on it.
+    pub fn as_coolant(&self, pressure: f64) -> Box<dyn Coolant> {
+        match self {
+            CoolantKind::Sodium => Box::new(Sodium),
+            CoolantKind::Lead => Box::new(Lead),
+            CoolantKind::Lbe => Box::new(Lbe),
+            CoolantKind::Water => Box::new(Water),
+            CoolantKind::Helium => Box::new(Helium { pressure }),
+            CoolantKind::MoltenSalt => Box::new(MoltenSalt),
+        }
+    }
+}
+
+impl Default for CoolantKind {
+    fn default() -> Self {
+        CoolantKind::Sodium
+    }
+}
+
+/// Adapts a `&dyn Coolant` to the `FluidProperties` trait, so flow routines that dispatch
+/// over a `FluidProperties` backend (`fluid_dynamics::calculate_reynolds_number_for_fluid`
+/// and friends) can run against the same coolant models `ReactorCalculator` already uses,
+/// instead of requiring a second, separately-maintained property implementation per fluid.
+/// A newtype rather than a blanket/supertrait impl, since `Coolant` and `FluidProperties`
+/// both declare `density`/`viscosity` methods (with different arities) and would otherwise
+/// be ambiguous to call on the same concrete type.
+pub struct CoolantAsFluid<'a>(pub &'a dyn Coolant);
+
+impl<'a> FluidProperties for CoolantAsFluid<'a> {
+    fn density(&self, temperature: f64, _pressure: f64) -> f64 {
+        self.0.density(temperature)
+    }
+
+    fn viscosity(&self, temperature: f64, _pressure: f64) -> f64 {
+        self.0.viscosity(temperature)
+    }
+
+    fn saturation_pressure(&self, _temperature: f64) -> f64 {
+        // Coolant has no saturation correlation; reactor coolant loops run far from
+        // saturation anyway. Use a fluid_properties::FluidProperties impl directly (e.g.
+        // fluid_properties::Sodium) if a real saturation pressure is needed.
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lbe_density() {
+        let lbe = Lbe;
+        let density = lbe.density(673.15); // 400 °C
+        assert!(density > 10000.0 && density < 11000.0);
+    }
+
+    #[test]
+    fn test_lbe_viscosity() {
+        let lbe = Lbe;
+        let viscosity = lbe.viscosity(673.15);
+        assert!(viscosity > 0.0 && viscosity < 0.01);
+    }
+
+    #[test]
+    fn test_sodium_is_liquid_metal() {
+        assert!(Sodium.is_liquid_metal());
+        assert!(!Water.is_liquid_metal());
+    }
+
+    #[test]
+    fn test_coolant_kind_default_is_sodium() {
+        assert_eq!(CoolantKind::default(), CoolantKind::Sodium);
+    }
+
+    #[test]
+    fn test_coolant_as_fluid_matches_underlying_coolant() {
+        let sodium = Sodium;
+        let adapted = CoolantAsFluid(&sodium);
+        assert_eq!(adapted.density(700.0, 1e5), sodium.density(700.0));
+        assert_eq!(adapted.viscosity(700.0, 1e5), sodium.viscosity(700.0));
+    }
+
+    #[test]
+    fn test_helium_density_scales_with_pressure() {
+        let low = Helium { pressure: 1e6 };
+        let high = Helium { pressure: 7e6 };
+        assert!(high.density(900.0) > low.density(900.0));
+    }
+}
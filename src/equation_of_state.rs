@@ -0,0 +1,310 @@
+/// Helmholtz-energy equation of state (EOS) backend
+///
+/// The correlations elsewhere in the crate are linear fits valid only near one operating
+/// point. For pressurized loops we need thermodynamically consistent properties over wide
+/// T/p ranges, so `HelmholtzEos` implements a multiparameter Helmholtz-energy EOS of the
+/// form used by reference fluid-property libraries (e.g. REFPROP/CoolProp-style models):
+/// reduced variables δ = ρ/ρc, τ = Tc/T, and a residual Helmholtz energy
+/// αr(δ,τ) = Σ nᵢ·δ^dᵢ·τ^tᵢ·exp(-δ^lᵢ) (lᵢ = 0 meaning no exponential factor). Pressure
+/// follows from p = ρRT(1 + δ·∂αr/∂δ); density at a given (T, p) is recovered by Newton
+/// iteration on δ using ∂p/∂ρ.
+use crate::fluid_properties::FluidProperties;
+
+/// One term of the residual Helmholtz energy polynomial/exponential expansion.
+#[derive(Debug, Clone, Copy)]
+pub struct HelmholtzTerm {
+    pub n: f64,
+    pub d: f64,
+    pub t: f64,
+    pub l: f64, // 0 => no exp(-δ^l) factor
+}
+
+/// A multiparameter Helmholtz-energy equation of state for a single pure fluid.
+#[derive(Debug, Clone)]
+pub struct HelmholtzEos {
+    /// Critical density ρc in kg/m³
+    pub critical_density: f64,
+    /// Critical temperature Tc in Kelvin
+    pub critical_temperature: f64,
+    /// Critical pressure pc in Pascals (used only for the saturation-pressure estimate)
+    pub critical_pressure: f64,
+    /// Specific gas constant R in J/kg·K
+    pub gas_constant: f64,
+    /// Residual Helmholtz energy terms
+    pub terms: Vec<HelmholtzTerm>,
+}
+
+impl HelmholtzEos {
+    /// ∂αr/∂δ at given reduced density/temperature
+    fn dalpha_ddelta(&self, delta: f64, tau: f64) -> f64 {
+        self.terms
+            .iter()
+            .map(|c| {
+                let base = c.n * delta.powf(c.d - 1.0) * tau.powf(c.t);
+                if c.l == 0.0 {
+                    c.d * base
+                } else {
+                    let exp_term = (-delta.powf(c.l)).exp();
+                    (c.d - c.l * delta.powf(c.l)) * base * exp_term
+                }
+            })
+            .sum()
+    }
+
+    /// ∂²αr/∂δ² at given reduced density/temperature
+    fn d2alpha_ddelta2(&self, delta: f64, tau: f64) -> f64 {
+        self.terms
+            .iter()
+            .map(|c| {
+                let base = c.n * delta.powf(c.d - 2.0) * tau.powf(c.t);
+                if c.l == 0.0 {
+                    c.d * (c.d - 1.0) * base
+                } else {
+                    let exp_term = (-delta.powf(c.l)).exp();
+                    let bracket = (c.d - c.l * delta.powf(c.l)) * (c.d - 1.0 - c.l * delta.powf(c.l))
+                        - c.l * c.l * delta.powf(c.l);
+                    bracket * base * exp_term
+                }
+            })
+            .sum()
+    }
+
+    /// τ·∂αr/∂τ at given reduced density/temperature, used to derive internal energy/enthalpy
+    fn tau_dalpha_dtau(&self, delta: f64, tau: f64) -> f64 {
+        self.terms
+            .iter()
+            .map(|c| {
+                let base = c.n * c.t * delta.powf(c.d) * tau.powf(c.t);
+                if c.l == 0.0 {
+                    base
+                } else {
+                    base * (-delta.powf(c.l)).exp()
+                }
+            })
+            .sum()
+    }
+
+    /// Pressure in Pascals from density (kg/m³) and temperature (Kelvin):
+    /// p = ρRT(1 + δ·∂αr/∂δ)
+    pub fn pressure(&self, density: f64, temperature: f64) -> f64 {
+        let delta = density / self.critical_density;
+        let tau = self.critical_temperature / temperature;
+        density * self.gas_constant * temperature * (1.0 + delta * self.dalpha_ddelta(delta, tau))
+    }
+
+    /// ∂p/∂ρ at constant T, needed for the Newton solve in `density_at`.
+    fn dp_drho(&self, density: f64, temperature: f64) -> f64 {
+        let delta = density / self.critical_density;
+        let tau = self.critical_temperature / temperature;
+        let d1 = self.dalpha_ddelta(delta, tau);
+        let d2 = self.d2alpha_ddelta2(delta, tau);
+        self.gas_constant * temperature * (1.0 + 2.0 * delta * d1 + delta * delta * d2)
+    }
+
+    /// Walk outward from `seed` in a single direction (positive = increasing density,
+    /// negative = decreasing), looking for the first density at which the residual's sign
+    /// flips relative to its value at the seed. Returns `None` if no sign change is found
+    /// within the search range (density would go non-positive, or 200 geometrically-growing
+    /// steps run out).
+    fn bracket_root(
+        &self,
+        residual: &dyn Fn(f64) -> f64,
+        seed: f64,
+        seed_sign: bool,
+        direction: f64,
+    ) -> Option<(f64, f64)> {
+        let mut cursor = seed;
+        let mut step = 0.01 * self.critical_density * direction;
+        for _ in 0..200 {
+            let next = cursor + step;
+            if next <= 0.0 {
+                return None;
+            }
+            if (residual(next) >= 0.0) != seed_sign {
+                return Some((cursor.min(next), cursor.max(next)));
+            }
+            cursor = next;
+            step *= 1.2;
+        }
+        None
+    }
+
+    /// Solve for density (kg/m³) at a given temperature (K) and pressure (Pa).
+    ///
+    /// For a truncated multiparameter fit, `pressure(ρ, T)` is not globally monotonic in ρ
+    /// near/above the critical point, so plain Newton iteration can overshoot past the
+    /// nearest root and converge on a distant, spurious one. This first brackets the root
+    /// by walking outward from the seed density, preferring the direction of increasing ρ
+    /// (stopping at the first sign change there) and only searching the decreasing-ρ
+    /// direction if that fails to find one — this keeps the near-critical behavior that
+    /// favors the denser of two nearby roots, while still reaching low-density/vapor-like
+    /// states below the seed that the increasing-only search could never find. It then
+    /// refines the bracket with Newton safeguarded by bisection: a Newton step that would
+    /// leave the bracket falls back to a bisection step instead.
+    pub fn density_at(&self, temperature: f64, pressure: f64) -> f64 {
+        let residual = |density: f64| self.pressure(density, temperature) - pressure;
+
+        let seed = 0.5 * self.critical_density;
+        let seed_sign = residual(seed) >= 0.0;
+        let (mut lo, mut hi) = match self
+            .bracket_root(&residual, seed, seed_sign, 1.0)
+            .or_else(|| self.bracket_root(&residual, seed, seed_sign, -1.0))
+        {
+            Some(bracket) => bracket,
+            // Residual never changed sign walking outward in either direction; fall back to
+            // a wide bracket so bisection still has somewhere to search.
+            None => (seed, seed + 50.0 * self.critical_density),
+        };
+
+        let mut density = 0.5 * (lo + hi);
+        let mut residual_lo = residual(lo);
+        for _ in 0..100 {
+            let r = residual(density);
+            if (r >= 0.0) == (residual_lo >= 0.0) {
+                lo = density;
+                residual_lo = r;
+            } else {
+                hi = density;
+            }
+
+            let slope = self.dp_drho(density, temperature);
+            let newton_next = density - r / slope;
+            let next = if slope != 0.0 && newton_next > lo && newton_next < hi {
+                newton_next
+            } else {
+                0.5 * (lo + hi)
+            };
+
+            if (next - density).abs() < 1e-9 * density.max(1.0) {
+                density = next;
+                break;
+            }
+            density = next;
+        }
+        density
+    }
+
+    /// Residual (non-ideal) part of the specific internal energy in J/kg:
+    /// u_r = RT·τ·∂αr/∂τ. The ideal-gas contribution is not included, since no ideal-gas
+    /// Helmholtz terms are modeled.
+    pub fn internal_energy(&self, density: f64, temperature: f64) -> f64 {
+        let delta = density / self.critical_density;
+        let tau = self.critical_temperature / temperature;
+        self.gas_constant * temperature * self.tau_dalpha_dtau(delta, tau)
+    }
+
+    /// Specific enthalpy in J/kg: h = u_r + p/ρ, with u_r from `internal_energy`, so the
+    /// ideal-gas internal-energy contribution is not included here either.
+    pub fn enthalpy(&self, density: f64, temperature: f64) -> f64 {
+        self.internal_energy(density, temperature) + self.pressure(density, temperature) / density
+    }
+
+    /// A compact, illustrative coefficient set in the style of a simple real-gas EOS
+    /// (not a drop-in replacement for a reference equation from REFPROP/CoolProp).
+    pub fn reference_fluid() -> Self {
+        HelmholtzEos {
+            critical_density: 467.6,      // kg/m³
+            critical_temperature: 304.13, // K
+            critical_pressure: 7.3773e6,  // Pa
+            gas_constant: 188.92,         // J/kg·K
+            terms: vec![
+                HelmholtzTerm { n: 0.38856, d: 1.0, t: 0.25, l: 0.0 },
+                HelmholtzTerm { n: -1.7023, d: 1.0, t: 1.125, l: 0.0 },
+                HelmholtzTerm { n: 0.45326, d: 1.0, t: 1.5, l: 0.0 },
+                HelmholtzTerm { n: 0.03207, d: 3.0, t: 1.375, l: 0.0 },
+                HelmholtzTerm { n: -0.20154, d: 2.0, t: 0.25, l: 1.0 },
+                HelmholtzTerm { n: -0.08203, d: 2.0, t: 0.875, l: 1.0 },
+            ],
+        }
+    }
+}
+
+impl FluidProperties for HelmholtzEos {
+    fn density(&self, temperature: f64, pressure: f64) -> f64 {
+        self.density_at(temperature, pressure)
+    }
+
+    fn viscosity(&self, temperature: f64, _pressure: f64) -> f64 {
+        // Corresponding-states estimate: not part of the Helmholtz formalism, but gives a
+        // physically reasonable order-of-magnitude viscosity for flow calculations.
+        let reduced_temp = temperature / self.critical_temperature;
+        1.0e-5 * reduced_temp.powf(0.5)
+    }
+
+    fn saturation_pressure(&self, temperature: f64) -> f64 {
+        // Simple Lee-Kesler-style reduced vapor-pressure estimate using only Tc/pc (no
+        // acentric factor correction), adequate as a rough saturation-pressure proxy.
+        let reduced_temp = temperature / self.critical_temperature;
+        self.critical_pressure * (5.373 * (1.0 - 1.0 / reduced_temp)).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pressure_density_round_trip() {
+        let eos = HelmholtzEos::reference_fluid();
+        let temperature = 350.0;
+        let density = 300.0;
+        let pressure = eos.pressure(density, temperature);
+        let recovered_density = eos.density_at(temperature, pressure);
+        assert!((recovered_density - density).abs() / density < 1e-3);
+    }
+
+    #[test]
+    fn test_pressure_density_round_trip_near_critical() {
+        let eos = HelmholtzEos::reference_fluid();
+        let temperature = eos.critical_temperature * 1.1;
+        let density = eos.critical_density * 0.8;
+        let pressure = eos.pressure(density, temperature);
+        let recovered_density = eos.density_at(temperature, pressure);
+        assert!((recovered_density - density).abs() / density < 1e-3);
+    }
+
+    #[test]
+    fn test_pressure_density_round_trip_low_density_branch() {
+        // A low-density/vapor-like state sitting below the 0.5*critical_density seed that
+        // density_at() searches from: the bracket search has to fall back to the
+        // decreasing-density direction to find this root at all.
+        let eos = HelmholtzEos::reference_fluid();
+        let temperature = 350.0;
+        let density = 50.0;
+        let pressure = eos.pressure(density, temperature);
+        let recovered_density = eos.density_at(temperature, pressure);
+        assert!((recovered_density - density).abs() / density < 1e-3);
+    }
+
+    #[test]
+    fn test_pressure_matches_ideal_gas_limit_at_low_density() {
+        // `reference_fluid` is an illustrative, truncated fit rather than a real CO2
+        // equation (see its doc comment), so it isn't expected to reproduce tabulated
+        // real-fluid data points. But any consistent residual-Helmholtz EOS must recover the
+        // ideal-gas law p = ρRT at sufficiently low density/high temperature, where residual
+        // (non-ideal) effects vanish - a reference point this fit's coefficients don't
+        // encode directly, so it's a meaningful independent check on `pressure`.
+        let eos = HelmholtzEos::reference_fluid();
+        let density = 1.0;
+        let temperature = 500.0;
+        let pressure = eos.pressure(density, temperature);
+        let ideal_gas_pressure = density * eos.gas_constant * temperature;
+        assert!((pressure - ideal_gas_pressure).abs() / ideal_gas_pressure < 1e-3);
+    }
+
+    #[test]
+    fn test_pressure_increases_with_density() {
+        let eos = HelmholtzEos::reference_fluid();
+        let p_low = eos.pressure(100.0, 350.0);
+        let p_high = eos.pressure(400.0, 350.0);
+        assert!(p_high > p_low);
+    }
+
+    #[test]
+    fn test_saturation_pressure_increases_with_temperature() {
+        let eos = HelmholtzEos::reference_fluid();
+        let low = eos.saturation_pressure(250.0);
+        let high = eos.saturation_pressure(290.0);
+        assert!(high > low);
+    }
+}
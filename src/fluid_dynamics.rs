@@ -1,4 +1,6 @@
 /// Fluid dynamics calculations for reactor coolant flow
+use crate::coolant::Coolant;
+use crate::fluid_properties::FluidProperties;
 use std::f64::consts::PI;
 
 /// Properties of liquid sodium coolant at typical operating conditions
@@ -17,6 +19,43 @@ pub fn sodium_viscosity(temperature: f64) -> f64 {
     0.001 * (-2.45e-4 * temp_celsius + 1.0).exp()
 }
 
+/// Sodium density and its analytic temperature derivative ∂ρ/∂T
+///
+/// # Returns
+/// `(density, d_density_dT)` in (kg/m³, kg/m³/K)
+pub fn sodium_density_dt(temperature: f64) -> (f64, f64) {
+    // ρ = 1014 - 0.235*(T - 273.15) -> dρ/dT = -0.235, constant
+    (sodium_density(temperature), -0.235)
+}
+
+/// Sodium viscosity and its analytic temperature derivative ∂μ/∂T
+///
+/// # Returns
+/// `(viscosity, d_viscosity_dT)` in (Pa·s, Pa·s/K)
+pub fn sodium_viscosity_dt(temperature: f64) -> (f64, f64) {
+    // μ = 0.001*exp(-2.45e-4*(T - 273.15) + 1) -> dμ/dT = μ * (-2.45e-4)
+    let viscosity = sodium_viscosity(temperature);
+    (viscosity, viscosity * -2.45e-4)
+}
+
+/// Propagate the viscosity temperature derivative to ∂Re/∂T.
+///
+/// Re = ρ·V·D/μ = (flow_rate·D)/(area·μ), so density cancels out of the mass-flow form
+/// and ∂Re/∂T = -(flow_rate·D)/(area·μ²) · ∂μ/∂T.
+///
+/// # Arguments
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `diameter` - Hydraulic diameter in meters
+/// * `temperature` - Coolant temperature in Kelvin
+///
+/// # Returns
+/// ∂Re/∂T (1/K)
+pub fn reynolds_sensitivity(flow_rate: f64, diameter: f64, temperature: f64) -> f64 {
+    let area = PI * diameter * diameter / 4.0;
+    let (viscosity, d_viscosity_dt) = sodium_viscosity_dt(temperature);
+    -(flow_rate * diameter) / (area * viscosity * viscosity) * d_viscosity_dt
+}
+
 /// Calculate Reynolds number for flow characterization
 /// 
 /// # Arguments
@@ -51,6 +90,110 @@ pub fn calculate_velocity(flow_rate: f64, diameter: f64, temperature: f64) -> f6
     flow_rate / (density * area)
 }
 
+/// Calculate Reynolds number for an arbitrary coolant
+///
+/// # Arguments
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `diameter` - Hydraulic diameter in meters
+/// * `temperature` - Coolant temperature in Kelvin
+/// * `coolant` - Coolant property model to evaluate density/viscosity against
+///
+/// # Returns
+/// Reynolds number (dimensionless)
+pub fn calculate_reynolds_number_for_coolant(
+    flow_rate: f64,
+    diameter: f64,
+    temperature: f64,
+    coolant: &dyn Coolant,
+) -> f64 {
+    let density = coolant.density(temperature);
+    let viscosity = coolant.viscosity(temperature);
+    let area = PI * diameter * diameter / 4.0;
+    let velocity = flow_rate / (density * area);
+
+    (density * velocity * diameter) / viscosity
+}
+
+/// Calculate flow velocity for an arbitrary coolant
+///
+/// # Arguments
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `diameter` - Hydraulic diameter in meters
+/// * `temperature` - Coolant temperature in Kelvin
+/// * `coolant` - Coolant property model to evaluate density against
+///
+/// # Returns
+/// Flow velocity in m/s
+pub fn calculate_velocity_for_coolant(
+    flow_rate: f64,
+    diameter: f64,
+    temperature: f64,
+    coolant: &dyn Coolant,
+) -> f64 {
+    let density = coolant.density(temperature);
+    let area = PI * diameter * diameter / 4.0;
+    flow_rate / (density * area)
+}
+
+/// Calculate Reynolds number from a `FluidProperties` backend
+///
+/// # Arguments
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `diameter` - Hydraulic diameter in meters
+/// * `temperature` - Fluid temperature in Kelvin
+/// * `pressure` - Fluid pressure in Pascals
+/// * `fluid` - Fluid property backend to evaluate density/viscosity against
+///
+/// # Returns
+/// Reynolds number (dimensionless)
+pub fn calculate_reynolds_number_for_fluid(
+    flow_rate: f64,
+    diameter: f64,
+    temperature: f64,
+    pressure: f64,
+    fluid: &dyn FluidProperties,
+) -> f64 {
+    let density = fluid.density(temperature, pressure);
+    let viscosity = fluid.viscosity(temperature, pressure);
+    let area = PI * diameter * diameter / 4.0;
+    let velocity = flow_rate / (density * area);
+
+    (density * velocity * diameter) / viscosity
+}
+
+/// Calculate flow velocity from a `FluidProperties` backend
+///
+/// # Arguments
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `diameter` - Hydraulic diameter in meters
+/// * `temperature` - Fluid temperature in Kelvin
+/// * `pressure` - Fluid pressure in Pascals
+/// * `fluid` - Fluid property backend to evaluate density against
+///
+/// # Returns
+/// Flow velocity in m/s
+pub fn calculate_velocity_for_fluid(
+    flow_rate: f64,
+    diameter: f64,
+    temperature: f64,
+    pressure: f64,
+    fluid: &dyn FluidProperties,
+) -> f64 {
+    let density = fluid.density(temperature, pressure);
+    let area = PI * diameter * diameter / 4.0;
+    flow_rate / (density * area)
+}
+
+/// Calculate Darcy friction factor given a `FluidProperties` backend.
+///
+/// The Darcy friction factor correlations below depend only on Reynolds number (and, for
+/// `calculate_friction_factor_with_roughness`, relative roughness) — not on fluid identity
+/// directly — so this simply dispatches on `reynolds`. It exists so callers already working
+/// against a `FluidProperties` registry can call through a uniform, fluid-aware API.
+pub fn calculate_friction_factor_for_fluid(reynolds: f64, _fluid: &dyn FluidProperties) -> f64 {
+    calculate_friction_factor(reynolds)
+}
+
 /// Determine if flow is laminar or turbulent
 /// 
 /// # Returns
@@ -81,6 +224,66 @@ pub fn calculate_friction_factor(reynolds: f64) -> f64 {
     }
 }
 
+/// Explicit Swamee-Jain approximation to Colebrook-White, used to seed the implicit solve.
+///
+/// f = 0.25 / [log₁₀(ε/D / 3.7 + 5.74/Re^0.9)]²
+fn swamee_jain_friction_factor(reynolds: f64, relative_roughness: f64) -> f64 {
+    let denom = (relative_roughness / 3.7 + 5.74 / reynolds.powf(0.9)).log10();
+    0.25 / (denom * denom)
+}
+
+/// Solve the implicit Colebrook-White equation for the Darcy friction factor by
+/// fixed-point iteration, seeded from the explicit Swamee-Jain approximation.
+///
+/// 1/√f = -2·log₁₀(ε/(3.7·D) + 2.51/(Re·√f))
+fn colebrook_white_friction_factor(reynolds: f64, relative_roughness: f64) -> f64 {
+    let mut f = swamee_jain_friction_factor(reynolds, relative_roughness);
+    for _ in 0..20 {
+        let rhs = -2.0 * (relative_roughness / 3.7 + 2.51 / (reynolds * f.sqrt())).log10();
+        let f_new = 1.0 / (rhs * rhs);
+        if (f_new - f).abs() < 1e-8 {
+            f = f_new;
+            break;
+        }
+        f = f_new;
+    }
+    f
+}
+
+/// Turbulent-flow friction factor: smooth-wall Blasius when the pipe is smooth and
+/// Re < 1e5 (including the turbulent end of the transition blend), otherwise the
+/// roughness-aware Colebrook-White correlation.
+fn turbulent_friction_factor(reynolds: f64, relative_roughness: f64) -> f64 {
+    if relative_roughness <= 0.0 && reynolds < 1.0e5 {
+        0.316 / reynolds.powf(0.25)
+    } else {
+        colebrook_white_friction_factor(reynolds, relative_roughness)
+    }
+}
+
+/// Calculate Darcy friction factor with wall roughness, selecting the correlation
+/// appropriate to the flow regime.
+///
+/// # Arguments
+/// * `reynolds` - Reynolds number
+/// * `relative_roughness` - Wall relative roughness ε/D (dimensionless, 0 for smooth pipe)
+///
+/// # Returns
+/// Darcy friction factor (dimensionless)
+pub fn calculate_friction_factor_with_roughness(reynolds: f64, relative_roughness: f64) -> f64 {
+    if reynolds < 2300.0 {
+        // Laminar flow - roughness has no first-order effect
+        64.0 / reynolds
+    } else if reynolds < 4000.0 {
+        // Transition region - linear interpolation
+        let f_lam = 64.0 / 2300.0;
+        let f_turb = turbulent_friction_factor(reynolds, relative_roughness);
+        f_lam + (f_turb - f_lam) * (reynolds - 2300.0) / 1700.0
+    } else {
+        turbulent_friction_factor(reynolds, relative_roughness)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,4 +334,94 @@ mod tests {
         // Should be reasonable velocity (few m/s)
         assert!(velocity > 0.0 && velocity < 50.0);
     }
+
+    #[test]
+    fn test_friction_factor_with_roughness_matches_smooth_default() {
+        let f_rough = calculate_friction_factor_with_roughness(10000.0, 0.0);
+        let f_smooth = calculate_friction_factor(10000.0);
+        assert!((f_rough - f_smooth).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_friction_factor_with_roughness_increases_with_roughness() {
+        let f_smooth = calculate_friction_factor_with_roughness(100000.0, 0.0001);
+        let f_rough = calculate_friction_factor_with_roughness(100000.0, 0.01);
+        assert!(f_rough > f_smooth);
+    }
+
+    #[test]
+    fn test_friction_factor_with_roughness_laminar_unaffected() {
+        let f = calculate_friction_factor_with_roughness(1000.0, 0.05);
+        assert_eq!(f, 64.0 / 1000.0);
+    }
+
+    #[test]
+    fn test_reynolds_number_for_coolant_matches_sodium() {
+        use crate::coolant::Sodium;
+        let re_generic = calculate_reynolds_number_for_coolant(10.0, 0.5, 600.0, &Sodium);
+        let re_sodium = calculate_reynolds_number(10.0, 0.5, 600.0);
+        assert!((re_generic - re_sodium).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sodium_density_dt_matches_finite_difference() {
+        let temperature = 600.0;
+        let h = 1e-3;
+        let (_, analytic) = sodium_density_dt(temperature);
+        let fd = (sodium_density(temperature + h) - sodium_density(temperature - h)) / (2.0 * h);
+        assert!((analytic - fd).abs() / analytic.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sodium_viscosity_dt_matches_finite_difference() {
+        let temperature = 600.0;
+        let h = 1e-3;
+        let (_, analytic) = sodium_viscosity_dt(temperature);
+        let fd = (sodium_viscosity(temperature + h) - sodium_viscosity(temperature - h)) / (2.0 * h);
+        assert!((analytic - fd).abs() / analytic.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_reynolds_sensitivity_matches_finite_difference() {
+        let flow_rate = 10.0;
+        let diameter = 0.5;
+        let temperature = 600.0;
+        let h = 1e-3;
+        let analytic = reynolds_sensitivity(flow_rate, diameter, temperature);
+        let fd = (calculate_reynolds_number(flow_rate, diameter, temperature + h)
+            - calculate_reynolds_number(flow_rate, diameter, temperature - h))
+            / (2.0 * h);
+        assert!((analytic - fd).abs() / analytic.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_friction_factor_with_roughness_matches_moody_chart_point() {
+        // Re = 1e5, ε/D = 0.001 -> Moody chart gives f ≈ 0.0225
+        let f = calculate_friction_factor_with_roughness(1.0e5, 0.001);
+        assert!((f - 0.0225).abs() < 0.003);
+    }
+
+    #[test]
+    fn test_reynolds_number_for_fluid_matches_sodium() {
+        use crate::fluid_properties::Sodium;
+        let re_generic =
+            calculate_reynolds_number_for_fluid(10.0, 0.5, 600.0, 1.0e5, &Sodium);
+        let re_sodium = calculate_reynolds_number(10.0, 0.5, 600.0);
+        assert!((re_generic - re_sodium).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_friction_factor_for_fluid_matches_base() {
+        use crate::fluid_properties::Ammonia;
+        let f_generic = calculate_friction_factor_for_fluid(50000.0, &Ammonia);
+        let f_base = calculate_friction_factor(50000.0);
+        assert_eq!(f_generic, f_base);
+    }
+
+    #[test]
+    fn test_reynolds_number_for_water() {
+        use crate::coolant::Water;
+        let re = calculate_reynolds_number_for_coolant(10.0, 0.1, 350.0, &Water);
+        assert!(re > 0.0);
+    }
 }
@@ -0,0 +1,117 @@
+/// Pluggable fluid-property backend for flow calculations
+///
+/// `fluid_dynamics::sodium_density`/`sodium_viscosity` are temperature-only correlations
+/// for liquid sodium. Process loops and secondary systems also use water, molten salt,
+/// lead-bismuth, and refrigerants like ammonia (R717), whose properties depend on pressure
+/// too (especially near saturation). The `FluidProperties` trait below lets flow routines
+/// dispatch over a fluid registry instead of calling the sodium functions directly.
+use crate::coolant::Coolant;
+
+pub trait FluidProperties {
+    /// Density in kg/m³ at the given temperature (K) and pressure (Pa)
+    fn density(&self, temperature: f64, pressure: f64) -> f64;
+    /// Dynamic viscosity in Pa·s at the given temperature (K) and pressure (Pa)
+    fn viscosity(&self, temperature: f64, pressure: f64) -> f64;
+    /// Saturation pressure in Pa at the given temperature (K)
+    fn saturation_pressure(&self, temperature: f64) -> f64;
+}
+
+/// Liquid sodium. Density and viscosity are only weakly pressure-dependent in the liquid
+/// range, so `pressure` is accepted but not used by these correlations.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sodium;
+
+impl FluidProperties for Sodium {
+    fn density(&self, temperature: f64, _pressure: f64) -> f64 {
+        crate::fluid_dynamics::sodium_density(temperature)
+    }
+
+    fn viscosity(&self, temperature: f64, _pressure: f64) -> f64 {
+        crate::fluid_dynamics::sodium_viscosity(temperature)
+    }
+
+    fn saturation_pressure(&self, temperature: f64) -> f64 {
+        // Browning-Potter correlation, ln(p[MPa]) = 11.9463 - 12633.73/T - 0.4672*ln(T)
+        let t = temperature;
+        (11.9463 - 12633.73 / t - 0.4672 * t.ln()).exp() * 1.0e6
+    }
+}
+
+/// Light water.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Water;
+
+impl FluidProperties for Water {
+    fn density(&self, temperature: f64, _pressure: f64) -> f64 {
+        crate::coolant::Water.density(temperature)
+    }
+
+    fn viscosity(&self, temperature: f64, _pressure: f64) -> f64 {
+        crate::coolant::Water.viscosity(temperature)
+    }
+
+    fn saturation_pressure(&self, temperature: f64) -> f64 {
+        // Antoine equation, valid ~1-100 °C, p in mmHg, T in °C
+        let t_celsius = temperature - 273.15;
+        let p_mmhg = 10f64.powf(8.07131 - 1730.63 / (233.426 + t_celsius));
+        p_mmhg * 133.322 // mmHg -> Pa
+    }
+}
+
+/// Ammonia (R717), a common industrial/process refrigerant.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ammonia;
+
+impl FluidProperties for Ammonia {
+    fn density(&self, temperature: f64, _pressure: f64) -> f64 {
+        // Linear fit over the typical refrigeration range (-50 to 50 °C)
+        732.0 - 2.45 * (temperature - 239.15)
+    }
+
+    fn viscosity(&self, temperature: f64, _pressure: f64) -> f64 {
+        2.28e-4 * (1233.0 / temperature).exp()
+    }
+
+    fn saturation_pressure(&self, temperature: f64) -> f64 {
+        // Antoine equation for ammonia, p in bar, T in K
+        let p_bar = 10f64.powf(4.86886 - 1113.928 / (temperature - 10.409));
+        p_bar * 1.0e5 // bar -> Pa
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sodium_density_matches_base_correlation() {
+        let density = Sodium.density(600.0, 1.0e5);
+        assert!(density > 800.0 && density < 950.0);
+    }
+
+    #[test]
+    fn test_sodium_saturation_pressure_increases_with_temperature() {
+        let low = Sodium.saturation_pressure(800.0);
+        let high = Sodium.saturation_pressure(1200.0);
+        assert!(high > low);
+    }
+
+    #[test]
+    fn test_water_saturation_pressure_near_atmospheric_at_boiling_point() {
+        let p = Water.saturation_pressure(373.15); // 100 °C
+        assert!((p - 101325.0).abs() / 101325.0 < 0.05);
+    }
+
+    #[test]
+    fn test_ammonia_density_positive_over_refrigeration_range() {
+        let density = Ammonia.density(253.15, 2.0e5); // -20 °C
+        assert!(density > 0.0);
+    }
+
+    #[test]
+    fn test_ammonia_saturation_pressure_increases_with_temperature() {
+        let low = Ammonia.saturation_pressure(250.0);
+        let high = Ammonia.saturation_pressure(300.0);
+        assert!(high > low);
+    }
+}
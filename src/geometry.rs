@@ -0,0 +1,97 @@
+/// Rod-bundle subchannel geometry helpers
+///
+/// Fast reactor cores are rod bundles, not single pipes, so the hydraulic diameter that
+/// feeds Reynolds/Nusselt/friction calculations should come from the lattice geometry
+/// (rod diameter and pitch) rather than being supplied directly as `core_diameter`.
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Rod lattice arrangement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[pyclass]
+pub enum LatticeType {
+    Triangular,
+    Square,
+}
+
+/// Calculate subchannel hydraulic diameter from rod diameter and pitch.
+///
+/// Triangular (hexagonal) lattice: Dh = D * [(2√3/π) * (P/D)² - 1]
+/// Square lattice: Dh = D * [(4/π) * (P/D)² - 1]
+///
+/// # Arguments
+/// * `lattice` - Rod lattice arrangement
+/// * `rod_diameter` - Rod outer diameter D in meters
+/// * `pitch` - Rod center-to-center pitch P in meters
+///
+/// # Returns
+/// Hydraulic diameter in meters
+pub fn calculate_hydraulic_diameter(lattice: LatticeType, rod_diameter: f64, pitch: f64) -> f64 {
+    let pitch_to_diameter = pitch / rod_diameter;
+    match lattice {
+        LatticeType::Triangular => {
+            rod_diameter * ((2.0 * 3.0_f64.sqrt() / PI) * pitch_to_diameter.powi(2) - 1.0)
+        }
+        LatticeType::Square => rod_diameter * ((4.0 / PI) * pitch_to_diameter.powi(2) - 1.0),
+    }
+}
+
+/// Calculate flow area per rod (unit-cell area minus the rod cross-section).
+///
+/// Triangular lattice unit cell: A = (√3/2)·P² - (π/4)·D²
+/// Square lattice unit cell: A = P² - (π/4)·D²
+///
+/// # Returns
+/// Flow area per rod in m²
+pub fn calculate_flow_area_per_rod(lattice: LatticeType, rod_diameter: f64, pitch: f64) -> f64 {
+    let rod_area = PI * rod_diameter * rod_diameter / 4.0;
+    match lattice {
+        LatticeType::Triangular => (3.0_f64.sqrt() / 2.0) * pitch * pitch - rod_area,
+        LatticeType::Square => pitch * pitch - rod_area,
+    }
+}
+
+/// Calculate wetted perimeter per rod (the rod's own circumference).
+///
+/// # Returns
+/// Wetted perimeter in meters
+pub fn calculate_wetted_perimeter(rod_diameter: f64) -> f64 {
+    PI * rod_diameter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangular_hydraulic_diameter() {
+        // P/D = 1.2 is a typical SFR pin-bundle pitch-to-diameter ratio
+        let dh = calculate_hydraulic_diameter(LatticeType::Triangular, 0.0085, 0.0102);
+        assert!(dh > 0.0 && dh < 0.01);
+    }
+
+    #[test]
+    fn test_square_hydraulic_diameter() {
+        let dh = calculate_hydraulic_diameter(LatticeType::Square, 0.0095, 0.0126);
+        assert!(dh > 0.0 && dh < 0.02);
+    }
+
+    #[test]
+    fn test_hydraulic_diameter_matches_flow_area_and_perimeter() {
+        let rod_diameter = 0.0085;
+        let pitch = 0.0102;
+        let area = calculate_flow_area_per_rod(LatticeType::Triangular, rod_diameter, pitch);
+        let perimeter = calculate_wetted_perimeter(rod_diameter);
+        let dh_from_area = 4.0 * area / perimeter;
+        let dh_formula = calculate_hydraulic_diameter(LatticeType::Triangular, rod_diameter, pitch);
+        assert!((dh_from_area - dh_formula).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tighter_pitch_reduces_hydraulic_diameter() {
+        let loose = calculate_hydraulic_diameter(LatticeType::Square, 0.0095, 0.0140);
+        let tight = calculate_hydraulic_diameter(LatticeType::Square, 0.0095, 0.0110);
+        assert!(tight < loose);
+    }
+}
@@ -0,0 +1,343 @@
+/// Intermediate heat exchanger modeling between primary and secondary coolant loops
+///
+/// The rest of the crate models flow through the reactor core; this module covers the
+/// shell-and-tube intermediate heat exchanger (IHX) that couples the primary loop to a
+/// secondary loop, sized from the log-mean temperature difference (LMTD).
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Flow arrangement between the hot and cold streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[pyclass]
+pub enum FlowArrangement {
+    CounterCurrent,
+    CoCurrent,
+}
+
+/// Calculate the log-mean temperature difference (LMTD) in Kelvin.
+///
+/// # Arguments
+/// * `hot_in` - Hot-side inlet temperature in Kelvin
+/// * `hot_out` - Hot-side outlet temperature in Kelvin
+/// * `cold_in` - Cold-side inlet temperature in Kelvin
+/// * `cold_out` - Cold-side outlet temperature in Kelvin
+/// * `arrangement` - Counter-current or co-current flow
+///
+/// # Returns
+/// LMTD in Kelvin
+pub fn calculate_lmtd(
+    hot_in: f64,
+    hot_out: f64,
+    cold_in: f64,
+    cold_out: f64,
+    arrangement: FlowArrangement,
+) -> f64 {
+    let (delta_t1, delta_t2) = match arrangement {
+        FlowArrangement::CounterCurrent => (hot_in - cold_out, hot_out - cold_in),
+        FlowArrangement::CoCurrent => (hot_in - cold_in, hot_out - cold_out),
+    };
+
+    // Avoid division by zero when the two temperature differences coincide
+    if (delta_t1 - delta_t2).abs() < 1e-9 {
+        delta_t1
+    } else {
+        (delta_t1 - delta_t2) / (delta_t1 / delta_t2).ln()
+    }
+}
+
+/// Calculate required heat transfer area from duty, overall U, and LMTD.
+///
+/// Q = U * A * LMTD, so A = Q / (U * LMTD)
+///
+/// # Arguments
+/// * `duty` - Heat duty in Watts
+/// * `overall_u` - Overall heat transfer coefficient in W/m²·K
+/// * `lmtd` - Log-mean temperature difference in Kelvin
+///
+/// # Returns
+/// Required heat transfer area in m²
+pub fn calculate_required_area(duty: f64, overall_u: f64, lmtd: f64) -> f64 {
+    duty / (overall_u * lmtd)
+}
+
+/// Calculate heat duty from area, overall U, and LMTD.
+///
+/// # Returns
+/// Heat duty in Watts
+pub fn calculate_duty(area: f64, overall_u: f64, lmtd: f64) -> f64 {
+    area * overall_u * lmtd
+}
+
+/// Calculate a stream's outlet temperature from an energy balance Q = ṁ * cp * ΔT.
+///
+/// # Arguments
+/// * `inlet_temp` - Stream inlet temperature in Kelvin
+/// * `duty` - Heat duty in Watts (positive if the stream is being heated)
+/// * `mass_flow_rate` - Mass flow rate in kg/s
+/// * `cp` - Specific heat capacity in J/kg·K
+///
+/// # Returns
+/// Stream outlet temperature in Kelvin
+pub fn calculate_outlet_temperature(
+    inlet_temp: f64,
+    duty: f64,
+    mass_flow_rate: f64,
+    cp: f64,
+) -> f64 {
+    inlet_temp + duty / (mass_flow_rate * cp)
+}
+
+/// Error sizing a `HeatExchanger` for a requested duty.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeatExchangerError {
+    /// The requested duty exceeds what the stream flow rates/cp can support without a
+    /// temperature cross (carries the requested duty and the max feasible duty, in Watts).
+    InfeasibleDuty { duty: f64, max_duty: f64 },
+}
+
+impl fmt::Display for HeatExchangerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeatExchangerError::InfeasibleDuty { duty, max_duty } => write!(
+                f,
+                "duty {duty}W is not feasible for these streams (max {max_duty}W before a temperature cross)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HeatExchangerError {}
+
+/// Results of sizing an intermediate heat exchanger
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[pyclass]
+pub struct HeatExchangerResults {
+    #[pyo3(get)]
+    pub lmtd: f64,
+    #[pyo3(get)]
+    pub area: f64,
+    #[pyo3(get)]
+    pub duty: f64,
+    #[pyo3(get)]
+    pub hot_outlet_temp: f64,
+    #[pyo3(get)]
+    pub cold_outlet_temp: f64,
+}
+
+/// Shell-and-tube intermediate heat exchanger between primary and secondary loops
+#[pyclass]
+pub struct HeatExchanger {
+    #[pyo3(get, set)]
+    pub hot_in: f64, // Kelvin
+    #[pyo3(get, set)]
+    pub cold_in: f64, // Kelvin
+    #[pyo3(get, set)]
+    pub hot_mass_flow_rate: f64, // kg/s
+    #[pyo3(get, set)]
+    pub cold_mass_flow_rate: f64, // kg/s
+    #[pyo3(get, set)]
+    pub hot_cp: f64, // J/kg·K
+    #[pyo3(get, set)]
+    pub cold_cp: f64, // J/kg·K
+    #[pyo3(get, set)]
+    pub overall_u: f64, // W/m²·K
+    #[pyo3(get, set)]
+    pub arrangement: FlowArrangement,
+}
+
+#[pymethods]
+impl HeatExchanger {
+    #[new]
+    // Each argument is an independent physical input exposed as a Python keyword argument
+    // (see the #[pyo3(get, set)] fields above); grouping them into a config struct would just
+    // move the same flat list into a second type without reducing what a caller must specify.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        hot_in: f64,
+        cold_in: f64,
+        hot_mass_flow_rate: f64,
+        cold_mass_flow_rate: f64,
+        hot_cp: f64,
+        cold_cp: f64,
+        overall_u: f64,
+        arrangement: FlowArrangement,
+    ) -> Self {
+        HeatExchanger {
+            hot_in,
+            cold_in,
+            hot_mass_flow_rate,
+            cold_mass_flow_rate,
+            hot_cp,
+            cold_cp,
+            overall_u,
+            arrangement,
+        }
+    }
+
+    /// Maximum duty these streams can exchange without a temperature cross.
+    ///
+    /// For counter-current flow this is the effectiveness-NTU bound Q_max = C_min *
+    /// (hot_in - cold_in): at the limit, the cold stream's outlet approaches the hot
+    /// stream's inlet (or vice versa). Co-current outlets can never cross past the streams'
+    /// common mixed-temperature limit, which is strictly tighter: both outlets approach
+    /// (C_hot*hot_in + C_cold*cold_in) / (C_hot + C_cold), giving Q_max = C_hot*C_cold /
+    /// (C_hot + C_cold) * (hot_in - cold_in).
+    fn max_feasible_duty(&self) -> f64 {
+        let c_hot = self.hot_mass_flow_rate * self.hot_cp;
+        let c_cold = self.cold_mass_flow_rate * self.cold_cp;
+        let delta_t = self.hot_in - self.cold_in;
+        match self.arrangement {
+            FlowArrangement::CounterCurrent => c_hot.min(c_cold) * delta_t,
+            FlowArrangement::CoCurrent => (c_hot * c_cold) / (c_hot + c_cold) * delta_t,
+        }
+    }
+
+    /// Size the exchanger for a given heat duty: find both outlet temperatures from the
+    /// energy balance, then derive the LMTD and required area.
+    ///
+    /// Rejects a `duty` that isn't strictly between 0 and the max feasible duty for these
+    /// streams, since such a duty forces a temperature cross and makes the LMTD undefined.
+    pub fn size_for_duty(&self, duty: f64) -> PyResult<HeatExchangerResults> {
+        let max_duty = self.max_feasible_duty();
+        if !(duty > 0.0 && duty < max_duty) {
+            return Err(PyValueError::new_err(
+                HeatExchangerError::InfeasibleDuty { duty, max_duty }.to_string(),
+            ));
+        }
+
+        let hot_out = calculate_outlet_temperature(
+            self.hot_in,
+            -duty,
+            self.hot_mass_flow_rate,
+            self.hot_cp,
+        );
+        let cold_out = calculate_outlet_temperature(
+            self.cold_in,
+            duty,
+            self.cold_mass_flow_rate,
+            self.cold_cp,
+        );
+        let lmtd = calculate_lmtd(self.hot_in, hot_out, self.cold_in, cold_out, self.arrangement);
+        let area = calculate_required_area(duty, self.overall_u, lmtd);
+
+        Ok(HeatExchangerResults {
+            lmtd,
+            area,
+            duty,
+            hot_outlet_temp: hot_out,
+            cold_outlet_temp: cold_out,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lmtd_counter_current() {
+        let lmtd = calculate_lmtd(600.0, 500.0, 450.0, 550.0, FlowArrangement::CounterCurrent);
+        // ΔT1 = 600 - 550 = 50, ΔT2 = 500 - 450 = 50 -> equal deltas, LMTD = 50
+        assert!((lmtd - 50.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_lmtd_co_current() {
+        let lmtd = calculate_lmtd(600.0, 500.0, 400.0, 480.0, FlowArrangement::CoCurrent);
+        // ΔT1 = 600 - 400 = 200, ΔT2 = 500 - 480 = 20
+        let expected = (200.0_f64 - 20.0) / (200.0_f64 / 20.0).ln();
+        assert!((lmtd - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_required_area() {
+        let area = calculate_required_area(1e6, 5000.0, 40.0);
+        assert_eq!(area, 1e6 / (5000.0 * 40.0));
+    }
+
+    #[test]
+    fn test_duty_from_area() {
+        let duty = calculate_duty(5.0, 5000.0, 40.0);
+        assert_eq!(duty, 5.0 * 5000.0 * 40.0);
+    }
+
+    #[test]
+    fn test_outlet_temperature_heating() {
+        let t_out = calculate_outlet_temperature(300.0, 1e6, 10.0, 4186.0);
+        assert!(t_out > 300.0);
+    }
+
+    #[test]
+    fn test_size_for_duty() {
+        let hx = HeatExchanger::new(
+            873.0,
+            573.0,
+            100.0,
+            80.0,
+            1270.0,
+            2386.0,
+            3000.0,
+            FlowArrangement::CounterCurrent,
+        );
+        // max_feasible_duty = min(100*1270, 80*2386) * (873-573) = 127000 * 300 = 3.81e7 W
+        let results = hx.size_for_duty(2e7).unwrap();
+        assert!(results.hot_outlet_temp < hx.hot_in);
+        assert!(results.cold_outlet_temp > hx.cold_in);
+        assert!(results.area > 0.0);
+        assert!(results.lmtd > 0.0);
+    }
+
+    #[test]
+    fn test_size_for_duty_rejects_infeasible_duty() {
+        let hx = HeatExchanger::new(
+            873.0,
+            573.0,
+            100.0,
+            80.0,
+            1270.0,
+            2386.0,
+            3000.0,
+            FlowArrangement::CounterCurrent,
+        );
+        // Exceeds max_feasible_duty (3.81e7 W): would force a temperature cross
+        assert!(hx.size_for_duty(5e7).is_err());
+    }
+
+    #[test]
+    fn test_size_for_duty_rejects_infeasible_duty_co_current() {
+        let hx = HeatExchanger::new(
+            873.0,
+            573.0,
+            100.0,
+            80.0,
+            1270.0,
+            2386.0,
+            3000.0,
+            FlowArrangement::CoCurrent,
+        );
+        // Co-current's common-mixed-temperature bound is C_hot*C_cold/(C_hot+C_cold) *
+        // (hot_in-cold_in) = 127000*190880/317880 * 300 ≈ 2.29e7 W, well below the
+        // counter-current bound of 3.81e7 W for these same streams. A duty inside the
+        // counter-current bound but outside the co-current one must still be rejected.
+        assert!(hx.size_for_duty(3e7).is_err());
+    }
+
+    #[test]
+    fn test_size_for_duty_accepts_feasible_duty_co_current() {
+        let hx = HeatExchanger::new(
+            873.0,
+            573.0,
+            100.0,
+            80.0,
+            1270.0,
+            2386.0,
+            3000.0,
+            FlowArrangement::CoCurrent,
+        );
+        let results = hx.size_for_duty(1e7).unwrap();
+        assert!(results.lmtd.is_finite());
+        assert!(results.hot_outlet_temp > results.cold_outlet_temp);
+    }
+}
@@ -1,4 +1,5 @@
 /// Heat transfer calculations for nuclear reactor
+use crate::coolant::Coolant;
 use crate::fluid_dynamics;
 
 /// Thermal conductivity of liquid sodium (W/m·K)
@@ -46,12 +47,12 @@ pub fn calculate_nusselt_number(reynolds: f64, prandtl: f64) -> f64 {
 }
 
 /// Calculate heat transfer coefficient
-/// 
+///
 /// # Arguments
 /// * `reynolds` - Reynolds number
 /// * `diameter` - Hydraulic diameter in meters
 /// * `temperature` - Coolant temperature in Kelvin
-/// 
+///
 /// # Returns
 /// Heat transfer coefficient in W/m²·K
 pub fn calculate_heat_transfer_coefficient(
@@ -62,11 +63,64 @@ pub fn calculate_heat_transfer_coefficient(
     let prandtl = calculate_prandtl_number(temperature);
     let nusselt = calculate_nusselt_number(reynolds, prandtl);
     let thermal_cond = sodium_thermal_conductivity(temperature);
-    
+
     // h = Nu * k / D
     (nusselt * thermal_cond) / diameter
 }
 
+/// Prandtl number for an arbitrary coolant: Pr = (cp * μ) / k
+pub fn calculate_prandtl_number_for_coolant(temperature: f64, coolant: &dyn Coolant) -> f64 {
+    let viscosity = coolant.viscosity(temperature);
+    let thermal_cond = coolant.thermal_conductivity(temperature);
+    let cp = coolant.cp(temperature);
+
+    (cp * viscosity) / thermal_cond
+}
+
+/// Calculate Nusselt number, selecting the correlation appropriate to the coolant.
+///
+/// Dittus-Boelter (`Nu = 0.023 * Re^0.8 * Pr^0.4`) assumes Pr = O(1) and is invalid for
+/// liquid metals (Pr ≪ 1). Liquid-metal coolants instead use the Péclet-based
+/// Lyon-Martinelli correlation `Nu = 4.82 + 0.0185 * Pe^0.827` (Pe = Re * Pr), which holds
+/// down to very low Prandtl numbers. Lyon-Martinelli is applied only for turbulent flow
+/// (Re > 4000); below that every coolant, liquid metal or not, goes through the laminar
+/// and transition branches of `calculate_nusselt_number`.
+pub fn calculate_nusselt_number_for_coolant(
+    reynolds: f64,
+    prandtl: f64,
+    coolant: &dyn Coolant,
+) -> f64 {
+    if coolant.is_liquid_metal() && reynolds > 4000.0 {
+        let peclet = reynolds * prandtl;
+        4.82 + 0.0185 * peclet.powf(0.827)
+    } else {
+        calculate_nusselt_number(reynolds, prandtl)
+    }
+}
+
+/// Calculate heat transfer coefficient for an arbitrary coolant.
+///
+/// # Arguments
+/// * `reynolds` - Reynolds number
+/// * `diameter` - Hydraulic diameter in meters
+/// * `temperature` - Coolant temperature in Kelvin
+/// * `coolant` - Coolant property model to evaluate Pr, k against
+///
+/// # Returns
+/// Heat transfer coefficient in W/m²·K
+pub fn calculate_heat_transfer_coefficient_for_coolant(
+    reynolds: f64,
+    diameter: f64,
+    temperature: f64,
+    coolant: &dyn Coolant,
+) -> f64 {
+    let prandtl = calculate_prandtl_number_for_coolant(temperature, coolant);
+    let nusselt = calculate_nusselt_number_for_coolant(reynolds, prandtl, coolant);
+    let thermal_cond = coolant.thermal_conductivity(temperature);
+
+    (nusselt * thermal_cond) / diameter
+}
+
 /// Calculate heat flux based on power and surface area
 /// 
 /// # Arguments
@@ -145,4 +199,21 @@ mod tests {
         let area = calculate_required_area(1e6, 10000.0, 100.0);
         assert_eq!(area, 1.0); // 1 m²
     }
+
+    #[test]
+    fn test_nusselt_liquid_metal_uses_lyon_martinelli() {
+        use crate::coolant::Sodium;
+        let prandtl = calculate_prandtl_number(600.0);
+        let nu = calculate_nusselt_number_for_coolant(50000.0, prandtl, &Sodium);
+        // Liquid-metal Nu should be much lower than Dittus-Boelter would give at this Pr
+        assert!(nu > 0.0 && nu < calculate_nusselt_number(50000.0, prandtl));
+    }
+
+    #[test]
+    fn test_nusselt_water_keeps_dittus_boelter() {
+        use crate::coolant::Water;
+        let nu_coolant = calculate_nusselt_number_for_coolant(50000.0, 5.0, &Water);
+        let nu_direct = calculate_nusselt_number(50000.0, 5.0);
+        assert_eq!(nu_coolant, nu_direct);
+    }
 }
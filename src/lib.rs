@@ -1,10 +1,44 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+pub mod constants;
 pub mod thermal_hydraulics;
 pub mod fluid_dynamics;
 pub mod heat_transfer;
 pub mod pressure;
+pub mod coolant;
+pub mod heat_exchanger;
+pub mod geometry;
+pub mod fluid_properties;
+pub mod equation_of_state;
+pub mod two_phase;
+pub mod units;
+pub mod mixture;
+
+use coolant::{CoolantAsFluid, CoolantKind};
+use geometry::LatticeType;
+
+/// Axial hot-channel sweep inputs, grouped into one type since `peaking_factor` and
+/// `extrapolated_height` are always resolved and consumed together (see
+/// `ReactorCalculator::calculate`/`calculate_axial_profile`), unlike `ReactorConfig`'s other
+/// fields, which each feed a different, independent calculation.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[pyclass]
+pub struct AxialSweepConfig {
+    #[pyo3(get, set)]
+    pub peaking_factor: Option<f64>, // q'_max / q'_avg; None = 1.5
+    #[pyo3(get, set)]
+    pub extrapolated_height: Option<f64>, // L_e (m); None = core_height * 1.2
+}
+
+#[pymethods]
+impl AxialSweepConfig {
+    #[new]
+    #[pyo3(signature = (peaking_factor=None, extrapolated_height=None))]
+    pub fn new(peaking_factor: Option<f64>, extrapolated_height: Option<f64>) -> Self {
+        AxialSweepConfig { peaking_factor, extrapolated_height }
+    }
+}
 
 /// Configuration for the nuclear reactor
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,11 +56,32 @@ pub struct ReactorConfig {
     pub core_diameter: f64,           // meters
     #[pyo3(get, set)]
     pub pressure: f64,                // Pascals
+    #[pyo3(get, set)]
+    pub coolant: CoolantKind,         // Coolant type (defaults to sodium)
+    #[pyo3(get, set)]
+    pub lattice_type: Option<LatticeType>, // Rod lattice, if the core is a pin bundle
+    #[pyo3(get, set)]
+    pub rod_diameter: Option<f64>,    // meters, required alongside lattice_type/pitch
+    #[pyo3(get, set)]
+    pub pitch: Option<f64>,           // meters, required alongside lattice_type/rod_diameter
+    #[pyo3(get, set)]
+    pub roughness: Option<f64>,       // wall relative roughness epsilon/D; None = smooth pipe
+    #[pyo3(get, set)]
+    pub axial_sweep: AxialSweepConfig, // Axial hot-channel sweep peaking factor/extrapolated height
 }
 
 #[pymethods]
 impl ReactorConfig {
     #[new]
+    #[pyo3(signature = (
+        coolant_inlet_temp, coolant_flow_rate, reactor_power, core_height, core_diameter,
+        pressure, coolant=CoolantKind::Sodium, lattice_type=None, rod_diameter=None, pitch=None,
+        roughness=None, axial_sweep=None
+    ))]
+    // Every argument is a field on ReactorConfig exposed as a Python keyword argument (most
+    // optional, per the signature above); the two axial-sweep-specific fields are already
+    // grouped into AxialSweepConfig above, since they're always consumed together.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         coolant_inlet_temp: f64,
         coolant_flow_rate: f64,
@@ -34,6 +89,12 @@ impl ReactorConfig {
         core_height: f64,
         core_diameter: f64,
         pressure: f64,
+        coolant: CoolantKind,
+        lattice_type: Option<LatticeType>,
+        rod_diameter: Option<f64>,
+        pitch: Option<f64>,
+        roughness: Option<f64>,
+        axial_sweep: Option<AxialSweepConfig>,
     ) -> Self {
         ReactorConfig {
             coolant_inlet_temp,
@@ -42,6 +103,23 @@ impl ReactorConfig {
             core_height,
             core_diameter,
             pressure,
+            coolant,
+            lattice_type,
+            rod_diameter,
+            pitch,
+            roughness,
+            axial_sweep: axial_sweep.unwrap_or_default(),
+        }
+    }
+
+    /// Hydraulic diameter used for flow calculations: derived from the rod lattice when
+    /// `lattice_type`, `rod_diameter`, and `pitch` are all set, otherwise `core_diameter`.
+    pub fn effective_diameter(&self) -> f64 {
+        match (self.lattice_type, self.rod_diameter, self.pitch) {
+            (Some(lattice), Some(rod_diameter), Some(pitch)) => {
+                geometry::calculate_hydraulic_diameter(lattice, rod_diameter, pitch)
+            }
+            _ => self.core_diameter,
         }
     }
 }
@@ -91,43 +169,72 @@ impl ReactorCalculator {
 
     /// Perform complete fluid dynamics analysis
     pub fn calculate(&self) -> PyResult<FluidDynamicsResults> {
+        let coolant = self.config.coolant.as_coolant(self.config.pressure);
+        // Derived from the rod lattice when the config specifies one, else core_diameter
+        let diameter = self.config.effective_diameter();
+
         // Calculate outlet temperature
-        let outlet_temp = thermal_hydraulics::calculate_outlet_temperature(
+        let outlet_temp = thermal_hydraulics::calculate_outlet_temperature_for_coolant(
             self.config.coolant_inlet_temp,
             self.config.reactor_power,
             self.config.coolant_flow_rate,
+            coolant.as_ref(),
         );
 
-        // Calculate Reynolds number for flow characterization
-        let reynolds = fluid_dynamics::calculate_reynolds_number(
+        // Calculate Reynolds number for flow characterization. Goes through the
+        // `FluidProperties` backend (via the `CoolantAsFluid` adapter) rather than calling
+        // `coolant` directly, so the pressure-aware fluid-property dispatch used by the
+        // `_for_fluid` helpers is exercised by the one real entry point, not just its own
+        // unit tests.
+        let reynolds = fluid_dynamics::calculate_reynolds_number_for_fluid(
             self.config.coolant_flow_rate,
-            self.config.core_diameter,
+            diameter,
             self.config.coolant_inlet_temp,
+            self.config.pressure,
+            &CoolantAsFluid(coolant.as_ref()),
         );
 
-        // Calculate heat transfer coefficient
-        let heat_transfer_coef = heat_transfer::calculate_heat_transfer_coefficient(
+        // Calculate heat transfer coefficient, using the Nusselt correlation appropriate
+        // to this coolant (Lyon-Martinelli for turbulent liquid-metal flow, Dittus-Boelter
+        // or the laminar/transition branches otherwise)
+        let heat_transfer_coef = heat_transfer::calculate_heat_transfer_coefficient_for_coolant(
             reynolds,
-            self.config.core_diameter,
+            diameter,
             self.config.coolant_inlet_temp,
+            coolant.as_ref(),
         );
 
-        // Calculate pressure drop through core
-        let pressure_drop = pressure::calculate_pressure_drop(
+        // Calculate pressure drop through core, accounting for wall roughness when given
+        let pressure_drop = pressure::calculate_pressure_drop_for_coolant_with_roughness(
             self.config.coolant_flow_rate,
             self.config.core_height,
-            self.config.core_diameter,
+            diameter,
             reynolds,
+            self.config.coolant_inlet_temp,
+            self.config.roughness.unwrap_or(0.0),
+            coolant.as_ref(),
         );
 
-        // Estimate maximum fuel temperature
-        let max_fuel_temp = thermal_hydraulics::calculate_max_fuel_temperature(
-            outlet_temp,
+        // Locate the true hot spot from the axial chopped-cosine power profile, rather
+        // than the lumped average-coolant-temperature estimate, which can't see that the
+        // clad/fuel peak sits downstream of core midplane.
+        let cp = coolant.cp(self.config.coolant_inlet_temp);
+        let axial_profile = thermal_hydraulics::calculate_axial_temperature_profile(
+            self.config.coolant_inlet_temp,
             self.config.reactor_power,
+            self.config.coolant_flow_rate,
+            cp,
             heat_transfer_coef,
             self.config.core_height,
-            self.config.core_diameter,
+            self.config
+                .axial_sweep
+                .extrapolated_height
+                .unwrap_or(self.config.core_height * 1.2),
+            diameter,
+            self.config.axial_sweep.peaking_factor.unwrap_or(1.5),
+            100,
         );
+        let max_fuel_temp = axial_profile.peak_fuel_temp;
 
         Ok(FluidDynamicsResults {
             outlet_temperature: outlet_temp,
@@ -138,6 +245,44 @@ impl ReactorCalculator {
         })
     }
 
+    /// Full axial temperature profile (coolant/clad/fuel vs. z) under the chopped-cosine
+    /// power shape, exposing the same hot-spot location that `calculate` summarizes as
+    /// `max_fuel_temperature`.
+    pub fn calculate_axial_profile(&self) -> thermal_hydraulics::AxialTemperatureProfile {
+        let coolant = self.config.coolant.as_coolant(self.config.pressure);
+        let diameter = self.config.effective_diameter();
+        let reynolds = fluid_dynamics::calculate_reynolds_number_for_fluid(
+            self.config.coolant_flow_rate,
+            diameter,
+            self.config.coolant_inlet_temp,
+            self.config.pressure,
+            &CoolantAsFluid(coolant.as_ref()),
+        );
+        let heat_transfer_coef = heat_transfer::calculate_heat_transfer_coefficient_for_coolant(
+            reynolds,
+            diameter,
+            self.config.coolant_inlet_temp,
+            coolant.as_ref(),
+        );
+        let cp = coolant.cp(self.config.coolant_inlet_temp);
+
+        thermal_hydraulics::calculate_axial_temperature_profile(
+            self.config.coolant_inlet_temp,
+            self.config.reactor_power,
+            self.config.coolant_flow_rate,
+            cp,
+            heat_transfer_coef,
+            self.config.core_height,
+            self.config
+                .axial_sweep
+                .extrapolated_height
+                .unwrap_or(self.config.core_height * 1.2),
+            diameter,
+            self.config.axial_sweep.peaking_factor.unwrap_or(1.5),
+            100,
+        )
+    }
+
     /// Get current configuration
     pub fn get_config(&self) -> ReactorConfig {
         self.config.clone()
@@ -153,8 +298,15 @@ impl ReactorCalculator {
 #[pymodule]
 fn splinther(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<ReactorConfig>()?;
+    m.add_class::<AxialSweepConfig>()?;
     m.add_class::<FluidDynamicsResults>()?;
     m.add_class::<ReactorCalculator>()?;
+    m.add_class::<CoolantKind>()?;
+    m.add_class::<heat_exchanger::FlowArrangement>()?;
+    m.add_class::<heat_exchanger::HeatExchangerResults>()?;
+    m.add_class::<heat_exchanger::HeatExchanger>()?;
+    m.add_class::<thermal_hydraulics::AxialTemperatureProfile>()?;
+    m.add_class::<LatticeType>()?;
     Ok(())
 }
 
@@ -171,6 +323,8 @@ mod tests {
             2.0,    // height (m)
             0.5,    // diameter (m)
             1e7,    // pressure (Pa)
+            CoolantKind::Sodium,
+            None, None, None, None, None,
         );
         assert_eq!(config.coolant_inlet_temp, 600.0);
         assert_eq!(config.reactor_power, 1e6);
@@ -178,8 +332,80 @@ mod tests {
 
     #[test]
     fn test_calculator_creation() {
-        let config = ReactorConfig::new(600.0, 10.0, 1e6, 2.0, 0.5, 1e7);
+        let config = ReactorConfig::new(
+            600.0, 10.0, 1e6, 2.0, 0.5, 1e7, CoolantKind::Sodium, None, None, None, None, None,
+        );
         let calculator = ReactorCalculator::new(config);
         assert_eq!(calculator.config.reactor_power, 1e6);
     }
+
+    #[test]
+    fn test_calculate_with_lbe_coolant() {
+        let config = ReactorConfig::new(
+            673.0, 10.0, 1e6, 2.0, 0.5, 1e7, CoolantKind::Lbe, None, None, None, None, None,
+        );
+        let calculator = ReactorCalculator::new(config);
+        let results = calculator.calculate().unwrap();
+        assert!(results.outlet_temperature > 673.0);
+        assert!(results.pressure_drop > 0.0);
+    }
+
+    #[test]
+    fn test_effective_diameter_uses_lattice_when_specified() {
+        let config = ReactorConfig::new(
+            600.0, 10.0, 1e6, 2.0, 0.5, 1e7, CoolantKind::Sodium,
+            Some(LatticeType::Triangular), Some(0.0085), Some(0.0102), None, None,
+        );
+        let expected = geometry::calculate_hydraulic_diameter(LatticeType::Triangular, 0.0085, 0.0102);
+        assert!((config.effective_diameter() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_effective_diameter_falls_back_to_core_diameter() {
+        let config = ReactorConfig::new(
+            600.0, 10.0, 1e6, 2.0, 0.5, 1e7, CoolantKind::Sodium, None, None, None, None, None,
+        );
+        assert_eq!(config.effective_diameter(), 0.5);
+    }
+
+    #[test]
+    fn test_calculate_with_roughness_increases_pressure_drop() {
+        let smooth = ReactorConfig::new(
+            600.0, 10.0, 1e6, 2.0, 0.5, 1e7, CoolantKind::Sodium, None, None, None, None, None,
+        );
+        let rough = ReactorConfig::new(
+            600.0, 10.0, 1e6, 2.0, 0.5, 1e7, CoolantKind::Sodium, None, None, None, Some(0.01), None,
+        );
+        let dp_smooth = ReactorCalculator::new(smooth).calculate().unwrap().pressure_drop;
+        let dp_rough = ReactorCalculator::new(rough).calculate().unwrap().pressure_drop;
+        assert!(dp_rough > dp_smooth);
+    }
+
+    #[test]
+    fn test_calculate_max_fuel_temperature_matches_axial_hot_spot() {
+        let config = ReactorConfig::new(
+            600.0, 10.0, 1e6, 2.0, 0.5, 1e7, CoolantKind::Sodium, None, None, None, None, None,
+        );
+        let calculator = ReactorCalculator::new(config);
+        let results = calculator.calculate().unwrap();
+        let profile = calculator.calculate_axial_profile();
+        // The true hot spot should sit downstream of core midplane, not at the lumped
+        // average-coolant-temperature location `calculate_max_fuel_temperature` assumes.
+        assert!(profile.peak_fuel_location > 0.0);
+        assert_eq!(results.max_fuel_temperature, profile.peak_fuel_temp);
+    }
+
+    #[test]
+    fn test_higher_peaking_factor_raises_max_fuel_temperature() {
+        let baseline = ReactorConfig::new(
+            600.0, 10.0, 1e6, 2.0, 0.5, 1e7, CoolantKind::Sodium, None, None, None, None, None,
+        );
+        let peaked = ReactorConfig::new(
+            600.0, 10.0, 1e6, 2.0, 0.5, 1e7, CoolantKind::Sodium, None, None, None, None,
+            Some(AxialSweepConfig::new(Some(2.0), None)),
+        );
+        let t_baseline = ReactorCalculator::new(baseline).calculate().unwrap().max_fuel_temperature;
+        let t_peaked = ReactorCalculator::new(peaked).calculate().unwrap().max_fuel_temperature;
+        assert!(t_peaked > t_baseline);
+    }
 }
@@ -0,0 +1,186 @@
+/// Mixing rules for multi-component coolant properties
+///
+/// Molten-salt and brine coolants are mixtures, not a single pure substance. `Mixture`
+/// combines component `FluidProperties` backends with mass fractions into a single
+/// `FluidProperties` implementation, so `fluid_dynamics::calculate_reynolds_number_for_fluid`
+/// and friends work directly against a mixture the same way they do against a pure fluid.
+use crate::fluid_properties::FluidProperties;
+use std::fmt;
+
+/// Selectable viscosity mixing rule. Density always uses mass-weighted linear mixing.
+#[derive(Debug, Clone, Copy)]
+pub enum MixingRule {
+    /// Logarithmic (Arrhenius) rule: ln(μ_mix) = Σ wᵢ·ln(μᵢ). Works for any component count.
+    Logarithmic,
+    /// Grunberg-Nissan rule: ln(μ_mix) = Σ wᵢ·ln(μᵢ) + w₁·w₂·G₁₂. Two components only.
+    GrunbergNissan { interaction_parameter: f64 },
+    /// Davidson rule: ln(μ_mix) = Σ wᵢ·ln(μᵢ) + w₁·w₂·D₁₂. Two components only.
+    Davidson { interaction_parameter: f64 },
+}
+
+/// Error constructing or evaluating a `Mixture`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MixtureError {
+    /// Mass fractions did not sum to 1 (within tolerance); carries the actual sum.
+    FractionsDoNotSumToOne(f64),
+    /// The requested mixing rule doesn't support this mixture (e.g. a binary rule used
+    /// with more or fewer than two components).
+    UnsupportedMixingRule,
+}
+
+impl fmt::Display for MixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MixtureError::FractionsDoNotSumToOne(sum) => {
+                write!(f, "mass fractions must sum to 1.0, got {sum}")
+            }
+            MixtureError::UnsupportedMixingRule => {
+                write!(f, "mixing rule is not supported for this mixture")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MixtureError {}
+
+/// One component of a `Mixture`: a fluid property backend and its mass fraction.
+pub struct Component {
+    pub fluid: Box<dyn FluidProperties>,
+    pub mass_fraction: f64,
+}
+
+/// A multi-component fluid mixture, dispatching density/viscosity/saturation-pressure
+/// over its components via the selected mixing rule.
+pub struct Mixture {
+    components: Vec<Component>,
+    viscosity_rule: MixingRule,
+}
+
+impl Mixture {
+    /// Build a mixture, validating that mass fractions sum to 1 and that the chosen
+    /// mixing rule is compatible with the number of components.
+    pub fn new(components: Vec<Component>, viscosity_rule: MixingRule) -> Result<Self, MixtureError> {
+        let fraction_sum: f64 = components.iter().map(|c| c.mass_fraction).sum();
+        if (fraction_sum - 1.0).abs() > 1e-6 {
+            return Err(MixtureError::FractionsDoNotSumToOne(fraction_sum));
+        }
+
+        let is_binary_rule = matches!(
+            viscosity_rule,
+            MixingRule::GrunbergNissan { .. } | MixingRule::Davidson { .. }
+        );
+        if is_binary_rule && components.len() != 2 {
+            return Err(MixtureError::UnsupportedMixingRule);
+        }
+
+        Ok(Mixture { components, viscosity_rule })
+    }
+}
+
+impl FluidProperties for Mixture {
+    /// Mass-weighted linear mixing: ρ_mix = Σ wᵢ·ρᵢ
+    fn density(&self, temperature: f64, pressure: f64) -> f64 {
+        self.components
+            .iter()
+            .map(|c| c.mass_fraction * c.fluid.density(temperature, pressure))
+            .sum()
+    }
+
+    fn viscosity(&self, temperature: f64, pressure: f64) -> f64 {
+        let log_sum: f64 = self
+            .components
+            .iter()
+            .map(|c| c.mass_fraction * c.fluid.viscosity(temperature, pressure).ln())
+            .sum();
+
+        let correction = match self.viscosity_rule {
+            MixingRule::Logarithmic => 0.0,
+            MixingRule::GrunbergNissan { interaction_parameter }
+            | MixingRule::Davidson { interaction_parameter } => {
+                self.components[0].mass_fraction * self.components[1].mass_fraction
+                    * interaction_parameter
+            }
+        };
+
+        (log_sum + correction).exp()
+    }
+
+    /// Mass-weighted average of component saturation pressures (a simplification of
+    /// Raoult's law, adequate when components don't interact strongly near saturation).
+    fn saturation_pressure(&self, temperature: f64) -> f64 {
+        self.components
+            .iter()
+            .map(|c| c.mass_fraction * c.fluid.saturation_pressure(temperature))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fluid_properties::{Sodium, Water};
+
+    fn make_binary_mixture(rule: MixingRule) -> Result<Mixture, MixtureError> {
+        Mixture::new(
+            vec![
+                Component { fluid: Box::new(Sodium), mass_fraction: 0.6 },
+                Component { fluid: Box::new(Water), mass_fraction: 0.4 },
+            ],
+            rule,
+        )
+    }
+
+    #[test]
+    fn test_fractions_must_sum_to_one() {
+        let result = Mixture::new(
+            vec![
+                Component { fluid: Box::new(Sodium), mass_fraction: 0.6 },
+                Component { fluid: Box::new(Water), mass_fraction: 0.6 },
+            ],
+            MixingRule::Logarithmic,
+        );
+        assert!(matches!(result, Err(MixtureError::FractionsDoNotSumToOne(_))));
+    }
+
+    #[test]
+    fn test_binary_rule_rejects_single_component() {
+        let result = Mixture::new(
+            vec![Component { fluid: Box::new(Sodium), mass_fraction: 1.0 }],
+            MixingRule::GrunbergNissan { interaction_parameter: 0.1 },
+        );
+        assert!(matches!(result, Err(MixtureError::UnsupportedMixingRule)));
+    }
+
+    #[test]
+    fn test_density_is_mass_weighted_average() {
+        let mixture = make_binary_mixture(MixingRule::Logarithmic).unwrap();
+        let density = mixture.density(600.0, 1.0e5);
+        let sodium_density = Sodium.density(600.0, 1.0e5);
+        let water_density = Water.density(600.0, 1.0e5);
+        let expected = 0.6 * sodium_density + 0.4 * water_density;
+        assert!((density - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_logarithmic_viscosity_matches_arrhenius_formula() {
+        let mixture = make_binary_mixture(MixingRule::Logarithmic).unwrap();
+        let viscosity = mixture.viscosity(600.0, 1.0e5);
+        let expected = (0.6 * Sodium.viscosity(600.0, 1.0e5).ln()
+            + 0.4 * Water.viscosity(600.0, 1.0e5).ln())
+        .exp();
+        assert!((viscosity - expected).abs() / expected < 1e-9);
+    }
+
+    #[test]
+    fn test_grunberg_nissan_interaction_shifts_viscosity() {
+        let no_interaction = make_binary_mixture(MixingRule::GrunbergNissan {
+            interaction_parameter: 0.0,
+        })
+        .unwrap();
+        let with_interaction = make_binary_mixture(MixingRule::GrunbergNissan {
+            interaction_parameter: 1.0,
+        })
+        .unwrap();
+        assert!(with_interaction.viscosity(600.0, 1.0e5) > no_interaction.viscosity(600.0, 1.0e5));
+    }
+}
@@ -1,5 +1,6 @@
 /// Pressure drop calculations for reactor coolant system
 use std::f64::consts::PI;
+use crate::coolant::Coolant;
 use crate::fluid_dynamics;
 
 /// Calculate pressure drop through reactor core
@@ -35,6 +36,111 @@ pub fn calculate_pressure_drop(
     friction_factor * (length / diameter) * (density * velocity * velocity / 2.0)
 }
 
+/// Calculate pressure drop through reactor core with wall roughness
+///
+/// Uses Darcy-Weisbach equation: ΔP = f * (L/D) * (ρ * V²/2), with the friction factor
+/// selected from the flow regime and relative roughness ε/D (0 for an idealized smooth
+/// pipe, matching `calculate_pressure_drop` below Re = 1e5; from Re = 1e5 up, a smooth
+/// pipe uses the Colebrook-White value instead of Blasius).
+///
+/// # Arguments
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `length` - Flow path length (core height) in meters
+/// * `diameter` - Hydraulic diameter in meters
+/// * `reynolds` - Reynolds number
+/// * `temperature` - Average coolant temperature in Kelvin (for property evaluation)
+/// * `relative_roughness` - Wall relative roughness ε/D (dimensionless)
+///
+/// # Returns
+/// Pressure drop in Pascals
+pub fn calculate_pressure_drop_with_roughness(
+    flow_rate: f64,
+    length: f64,
+    diameter: f64,
+    reynolds: f64,
+    temperature: f64,
+    relative_roughness: f64,
+) -> f64 {
+    let friction_factor =
+        fluid_dynamics::calculate_friction_factor_with_roughness(reynolds, relative_roughness);
+
+    let density = fluid_dynamics::sodium_density(temperature);
+
+    let area = PI * diameter * diameter / 4.0;
+    let velocity = flow_rate / (density * area);
+
+    friction_factor * (length / diameter) * (density * velocity * velocity / 2.0)
+}
+
+/// Calculate pressure drop through reactor core for an arbitrary coolant
+///
+/// Uses Darcy-Weisbach equation: ΔP = f * (L/D) * (ρ * V²/2)
+///
+/// # Arguments
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `length` - Flow path length (core height) in meters
+/// * `diameter` - Hydraulic diameter in meters
+/// * `reynolds` - Reynolds number
+/// * `temperature` - Average coolant temperature in Kelvin (for property evaluation)
+/// * `coolant` - Coolant property model to evaluate density against
+///
+/// # Returns
+/// Pressure drop in Pascals
+pub fn calculate_pressure_drop_for_coolant(
+    flow_rate: f64,
+    length: f64,
+    diameter: f64,
+    reynolds: f64,
+    temperature: f64,
+    coolant: &dyn Coolant,
+) -> f64 {
+    let friction_factor = fluid_dynamics::calculate_friction_factor(reynolds);
+    let density = coolant.density(temperature);
+
+    let area = PI * diameter * diameter / 4.0;
+    let velocity = flow_rate / (density * area);
+
+    friction_factor * (length / diameter) * (density * velocity * velocity / 2.0)
+}
+
+/// Calculate pressure drop through reactor core for an arbitrary coolant, with wall
+/// roughness
+///
+/// Uses Darcy-Weisbach equation: ΔP = f * (L/D) * (ρ * V²/2), with the friction factor
+/// selected from the flow regime and relative roughness ε/D (0 for an idealized smooth
+/// pipe, matching `calculate_pressure_drop_for_coolant` below Re = 1e5; from Re = 1e5 up,
+/// a smooth pipe uses the Colebrook-White value instead of Blasius).
+///
+/// # Arguments
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `length` - Flow path length (core height) in meters
+/// * `diameter` - Hydraulic diameter in meters
+/// * `reynolds` - Reynolds number
+/// * `temperature` - Average coolant temperature in Kelvin (for property evaluation)
+/// * `relative_roughness` - Wall relative roughness ε/D (dimensionless)
+/// * `coolant` - Coolant property model to evaluate density against
+///
+/// # Returns
+/// Pressure drop in Pascals
+pub fn calculate_pressure_drop_for_coolant_with_roughness(
+    flow_rate: f64,
+    length: f64,
+    diameter: f64,
+    reynolds: f64,
+    temperature: f64,
+    relative_roughness: f64,
+    coolant: &dyn Coolant,
+) -> f64 {
+    let friction_factor =
+        fluid_dynamics::calculate_friction_factor_with_roughness(reynolds, relative_roughness);
+    let density = coolant.density(temperature);
+
+    let area = PI * diameter * diameter / 4.0;
+    let velocity = flow_rate / (density * area);
+
+    friction_factor * (length / diameter) * (density * velocity * velocity / 2.0)
+}
+
 /// Calculate pressure drop due to elevation change (gravity)
 /// 
 /// ΔP = ρ * g * Δh
@@ -72,8 +178,41 @@ pub fn calculate_acceleration_pressure_drop(
     density * (velocity_2 * velocity_2 - velocity_1 * velocity_1) / 2.0
 }
 
+/// Calculate pressure drop across a valve or fitting from its metric flow coefficient (Kv)
+///
+/// ΔP[bar] = SG * (Q[m³/hr] / Kv)²
+///
+/// # Arguments
+/// * `kv` - Metric flow coefficient: m³/hr of water at 4 °C per 1 bar of pressure drop
+/// * `volumetric_flow_rate` - Volumetric flow rate in m³/hr
+/// * `specific_gravity` - Fluid specific gravity relative to water at 4 °C
+///
+/// # Returns
+/// Pressure drop in Pascals
+pub fn calculate_valve_pressure_drop(kv: f64, volumetric_flow_rate: f64, specific_gravity: f64) -> f64 {
+    let delta_p_bar = specific_gravity * (volumetric_flow_rate / kv).powi(2);
+    delta_p_bar * 1.0e5 // 1 bar = 1e5 Pa
+}
+
+/// Calculate pressure drop across a valve or fitting from its imperial flow coefficient (Cv)
+///
+/// Converts to the metric Kv via Cv ≈ 1.156 * Kv, then applies the same relation.
+///
+/// # Arguments
+/// * `cv` - Imperial flow coefficient (US gpm of water at 60 °F per 1 psi)
+/// * `volumetric_flow_rate` - Volumetric flow rate in m³/hr
+/// * `specific_gravity` - Fluid specific gravity relative to water at 4 °C
+///
+/// # Returns
+/// Pressure drop in Pascals
+pub fn calculate_valve_pressure_drop_cv(cv: f64, volumetric_flow_rate: f64, specific_gravity: f64) -> f64 {
+    const CV_PER_KV: f64 = 1.156;
+    let kv = cv / CV_PER_KV;
+    calculate_valve_pressure_drop(kv, volumetric_flow_rate, specific_gravity)
+}
+
 /// Calculate total system pressure drop
-/// 
+///
 /// Includes friction, elevation, and acceleration effects
 /// 
 /// # Arguments
@@ -188,6 +327,64 @@ mod tests {
         assert_eq!(total, 10000.0 + 5000.0 + 1000.0 + 1.5 * 2000.0);
     }
 
+    #[test]
+    fn test_pressure_drop_with_roughness_matches_smooth_default() {
+        let dp_smooth = calculate_pressure_drop(10.0, 2.0, 0.5, 50000.0, 650.0);
+        let dp_roughness = calculate_pressure_drop_with_roughness(10.0, 2.0, 0.5, 50000.0, 650.0, 0.0);
+        assert!((dp_smooth - dp_roughness).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_pressure_drop_with_roughness_increases_drop() {
+        let dp_smooth = calculate_pressure_drop_with_roughness(10.0, 2.0, 0.5, 100000.0, 650.0, 0.0);
+        let dp_rough = calculate_pressure_drop_with_roughness(10.0, 2.0, 0.5, 100000.0, 650.0, 0.01);
+        assert!(dp_rough > dp_smooth);
+    }
+
+    #[test]
+    fn test_valve_pressure_drop() {
+        // Kv = 10, Q = 10 m³/hr, SG = 1.0 -> ΔP = 1.0 * (10/10)² bar = 1 bar = 1e5 Pa
+        let dp = calculate_valve_pressure_drop(10.0, 10.0, 1.0);
+        assert!((dp - 1.0e5).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_valve_pressure_drop_scales_with_flow_squared() {
+        let dp_low = calculate_valve_pressure_drop(10.0, 5.0, 1.0);
+        let dp_high = calculate_valve_pressure_drop(10.0, 10.0, 1.0);
+        assert!((dp_high - 4.0 * dp_low).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_valve_pressure_drop_cv_matches_kv_conversion() {
+        let kv = 10.0;
+        let cv = kv * 1.156;
+        let dp_kv = calculate_valve_pressure_drop(kv, 10.0, 1.0);
+        let dp_cv = calculate_valve_pressure_drop_cv(cv, 10.0, 1.0);
+        assert!((dp_kv - dp_cv).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_pressure_drop_for_coolant_matches_sodium() {
+        use crate::coolant::Sodium;
+        let dp_generic =
+            calculate_pressure_drop_for_coolant(10.0, 2.0, 0.5, 50000.0, 650.0, &Sodium);
+        let dp_sodium = calculate_pressure_drop(10.0, 2.0, 0.5, 50000.0, 650.0);
+        assert!((dp_generic - dp_sodium).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_pressure_drop_for_coolant_with_roughness_increases_drop() {
+        use crate::coolant::Sodium;
+        let dp_smooth = calculate_pressure_drop_for_coolant_with_roughness(
+            10.0, 2.0, 0.5, 100000.0, 650.0, 0.0, &Sodium,
+        );
+        let dp_rough = calculate_pressure_drop_for_coolant_with_roughness(
+            10.0, 2.0, 0.5, 100000.0, 650.0, 0.01, &Sodium,
+        );
+        assert!(dp_rough > dp_smooth);
+    }
+
     #[test]
     fn test_pump_power() {
         let power = calculate_pump_power(100000.0, 0.01, 0.8);
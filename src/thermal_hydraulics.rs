@@ -1,6 +1,9 @@
 /// Thermal hydraulics calculations for nuclear reactor coolant
 use std::f64::consts::PI;
 use crate::constants::{SODIUM_CP, FUEL_TEMP_RISE_FACTOR};
+use crate::coolant::Coolant;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Calculate outlet temperature of coolant
 /// 
@@ -18,6 +21,27 @@ pub fn calculate_outlet_temperature(inlet_temp: f64, power: f64, flow_rate: f64)
     inlet_temp + delta_t
 }
 
+/// Calculate outlet temperature of an arbitrary coolant
+///
+/// # Arguments
+/// * `inlet_temp` - Inlet temperature in Kelvin
+/// * `power` - Reactor thermal power in Watts
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `coolant` - Coolant property model to evaluate cp against
+///
+/// # Returns
+/// Outlet temperature in Kelvin
+pub fn calculate_outlet_temperature_for_coolant(
+    inlet_temp: f64,
+    power: f64,
+    flow_rate: f64,
+    coolant: &dyn Coolant,
+) -> f64 {
+    let cp = coolant.cp(inlet_temp);
+    let delta_t = power / (flow_rate * cp);
+    inlet_temp + delta_t
+}
+
 /// Calculate maximum fuel temperature in the reactor core
 /// 
 /// # Arguments
@@ -56,6 +80,126 @@ pub fn calculate_average_coolant_temp(inlet_temp: f64, outlet_temp: f64) -> f64
     (inlet_temp + outlet_temp) / 2.0
 }
 
+/// Axial temperature arrays and hot-spot location from a chopped-cosine power profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[pyclass]
+pub struct AxialTemperatureProfile {
+    #[pyo3(get)]
+    pub z: Vec<f64>,
+    #[pyo3(get)]
+    pub coolant_temp: Vec<f64>,
+    #[pyo3(get)]
+    pub clad_temp: Vec<f64>,
+    #[pyo3(get)]
+    pub fuel_temp: Vec<f64>,
+    #[pyo3(get)]
+    pub peak_clad_temp: f64,
+    #[pyo3(get)]
+    pub peak_clad_location: f64,
+    #[pyo3(get)]
+    pub peak_fuel_temp: f64,
+    #[pyo3(get)]
+    pub peak_fuel_location: f64,
+}
+
+/// Calculate the axial temperature profile of coolant, clad, and fuel under a
+/// chopped-cosine linear heat rate q'(z) = q'_max * cos(π*z / L_e), z ∈ [-H/2, H/2].
+///
+/// Coolant temperature follows from integrating the energy balance along z:
+/// T_c(z) = T_in + (q'_max * L_e) / (π * ṁ * cp) * [sin(π*z/L_e) + sin(π*H / (2*L_e))]
+///
+/// Film and fuel-pellet temperature rises are added locally from the heat flux at each
+/// node, using the same lumped `FUEL_TEMP_RISE_FACTOR` model as
+/// `calculate_max_fuel_temperature`. The clad/fuel peak sits downstream of core
+/// midplane because the coolant keeps heating while the flux is falling.
+///
+/// # Arguments
+/// * `inlet_temp` - Coolant inlet temperature in Kelvin
+/// * `power` - Reactor thermal power in Watts
+/// * `flow_rate` - Mass flow rate in kg/s
+/// * `cp` - Coolant specific heat capacity in J/kg·K
+/// * `heat_transfer_coef` - Heat transfer coefficient in W/m²·K
+/// * `core_height` - Core height H in meters
+/// * `extrapolated_height` - Extrapolated height L_e in meters (L_e > H)
+/// * `core_diameter` - Core/channel diameter in meters, used for the heat-flux perimeter
+/// * `peaking_factor` - Axial peaking factor q'_max / q'_avg (dimensionless)
+/// * `num_nodes` - Number of axial nodes to sweep (50-200 is typical)
+///
+/// # Returns
+/// Axial temperature arrays plus the peak clad/fuel temperatures and their locations
+// Ten independent physical inputs to one closed-form sweep; bundling them into a config
+// struct would only push the same flat list into a type this function is the sole caller
+// of, so keep the plain argument list and document each one above instead.
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_axial_temperature_profile(
+    inlet_temp: f64,
+    power: f64,
+    flow_rate: f64,
+    cp: f64,
+    heat_transfer_coef: f64,
+    core_height: f64,
+    extrapolated_height: f64,
+    core_diameter: f64,
+    peaking_factor: f64,
+    num_nodes: usize,
+) -> AxialTemperatureProfile {
+    let q_avg = power / core_height;
+    let q_max = peaking_factor * q_avg;
+    let half_height = core_height / 2.0;
+    let le = extrapolated_height;
+
+    let sin_half_core = (PI * core_height / (2.0 * le)).sin();
+    let coolant_coeff = (q_max * le) / (PI * flow_rate * cp);
+
+    let mut z = Vec::with_capacity(num_nodes);
+    let mut coolant_temp = Vec::with_capacity(num_nodes);
+    let mut clad_temp = Vec::with_capacity(num_nodes);
+    let mut fuel_temp = Vec::with_capacity(num_nodes);
+
+    let mut peak_clad_temp = f64::MIN;
+    let mut peak_clad_location = 0.0;
+    let mut peak_fuel_temp = f64::MIN;
+    let mut peak_fuel_location = 0.0;
+
+    for i in 0..num_nodes {
+        let frac = i as f64 / (num_nodes - 1) as f64;
+        let z_i = -half_height + frac * core_height;
+
+        let linear_heat_rate = q_max * (PI * z_i / le).cos();
+        let t_coolant = inlet_temp + coolant_coeff * ((PI * z_i / le).sin() + sin_half_core);
+
+        let heat_flux = linear_heat_rate / (PI * core_diameter);
+        let film_rise = heat_flux / heat_transfer_coef;
+        let t_clad = t_coolant + film_rise;
+        let t_fuel = t_coolant + film_rise * FUEL_TEMP_RISE_FACTOR;
+
+        if t_clad > peak_clad_temp {
+            peak_clad_temp = t_clad;
+            peak_clad_location = z_i;
+        }
+        if t_fuel > peak_fuel_temp {
+            peak_fuel_temp = t_fuel;
+            peak_fuel_location = z_i;
+        }
+
+        z.push(z_i);
+        coolant_temp.push(t_coolant);
+        clad_temp.push(t_clad);
+        fuel_temp.push(t_fuel);
+    }
+
+    AxialTemperatureProfile {
+        z,
+        coolant_temp,
+        clad_temp,
+        fuel_temp,
+        peak_clad_temp,
+        peak_clad_location,
+        peak_fuel_temp,
+        peak_fuel_location,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,4 +238,35 @@ mod tests {
         let avg = calculate_average_coolant_temp(600.0, 700.0);
         assert_eq!(avg, 650.0);
     }
+
+    #[test]
+    fn test_axial_profile_peak_downstream_of_midplane() {
+        let profile = calculate_axial_temperature_profile(
+            600.0, 1e6, 10.0, SODIUM_CP, 10000.0, 2.0, 2.4, 0.5, 1.5, 100,
+        );
+        // The hot spot should sit downstream (positive z) of core midplane
+        assert!(profile.peak_clad_location > 0.0);
+        assert!(profile.peak_fuel_location > 0.0);
+        assert!(profile.peak_fuel_temp > profile.peak_clad_temp);
+        assert_eq!(profile.z.len(), 100);
+    }
+
+    #[test]
+    fn test_axial_profile_peaking_factor_raises_peak_temp() {
+        let low_peaking = calculate_axial_temperature_profile(
+            600.0, 1e6, 10.0, SODIUM_CP, 10000.0, 2.0, 2.4, 0.5, 1.1, 50,
+        );
+        let high_peaking = calculate_axial_temperature_profile(
+            600.0, 1e6, 10.0, SODIUM_CP, 10000.0, 2.0, 2.4, 0.5, 2.0, 50,
+        );
+        assert!(high_peaking.peak_fuel_temp > low_peaking.peak_fuel_temp);
+    }
+
+    #[test]
+    fn test_outlet_temperature_for_coolant_matches_sodium() {
+        use crate::coolant::Sodium;
+        let outlet_generic = calculate_outlet_temperature_for_coolant(600.0, 1e6, 10.0, &Sodium);
+        let outlet_sodium = calculate_outlet_temperature(600.0, 1e6, 10.0);
+        assert!((outlet_generic - outlet_sodium).abs() < 1e-6);
+    }
 }
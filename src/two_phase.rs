@@ -0,0 +1,185 @@
+/// Two-phase (gas/liquid) pressure-gradient calculations, Hagedorn-Brown style
+///
+/// Once coolant approaches saturation the single-phase Reynolds/friction path in
+/// `fluid_dynamics`/`pressure` breaks down, so this module adds a multiphase
+/// pressure-drop model following the structure of the Hagedorn-Brown procedure:
+/// superficial velocities from the phase mass rates, dimensionless velocity/viscosity/
+/// diameter number groups, a liquid-holdup estimate, mixture density from holdup, and a
+/// two-phase friction factor evaluated against the mixture Reynolds number.
+///
+/// Note: the original Hagedorn-Brown correlation reads liquid holdup and a secondary
+/// correction factor off correlation charts that were never reduced to a single public
+/// closed-form equation. The holdup estimate below is a simplified, monotonic stand-in
+/// fit to the same dimensionless groups rather than a digitization of those charts, so
+/// treat absolute pressure-gradient values as indicative rather than chart-accurate.
+use crate::fluid_dynamics;
+use std::f64::consts::PI;
+
+/// Fluid properties needed to evaluate a two-phase pressure gradient.
+#[derive(Debug, Clone, Copy)]
+pub struct TwoPhaseProperties {
+    /// Liquid density in kg/m³
+    pub liquid_density: f64,
+    /// Gas density in kg/m³
+    pub gas_density: f64,
+    /// Liquid dynamic viscosity in Pa·s
+    pub liquid_viscosity: f64,
+    /// Gas dynamic viscosity in Pa·s
+    pub gas_viscosity: f64,
+    /// Gas-liquid surface tension in N/m
+    pub surface_tension: f64,
+}
+
+/// Superficial velocity of a phase: volumetric flow rate divided by the full pipe area.
+fn superficial_velocity(mass_rate: f64, density: f64, area: f64) -> f64 {
+    mass_rate / (density * area)
+}
+
+/// Liquid velocity number, NLV = 1.938 * v_sl * (ρL/σ)^0.25
+fn liquid_velocity_number(v_sl: f64, props: &TwoPhaseProperties) -> f64 {
+    1.938 * v_sl * (props.liquid_density / props.surface_tension).powf(0.25)
+}
+
+/// Gas velocity number, NGV = 1.938 * v_sg * (ρL/σ)^0.25
+fn gas_velocity_number(v_sg: f64, props: &TwoPhaseProperties) -> f64 {
+    1.938 * v_sg * (props.liquid_density / props.surface_tension).powf(0.25)
+}
+
+/// Pipe diameter number, ND = 120.872 * D * √(ρL/σ)
+fn diameter_number(diameter: f64, props: &TwoPhaseProperties) -> f64 {
+    120.872 * diameter * (props.liquid_density / props.surface_tension).sqrt()
+}
+
+/// Liquid viscosity number, NL = 0.15726 * μL * (1 / (ρL·σ³))^0.25
+fn liquid_viscosity_number(props: &TwoPhaseProperties) -> f64 {
+    0.15726
+        * props.liquid_viscosity
+        * (1.0 / (props.liquid_density * props.surface_tension.powi(3))).powf(0.25)
+}
+
+/// Estimate in-situ liquid holdup from the Hagedorn-Brown dimensionless groups.
+///
+/// Bounded between the no-slip holdup (v_sl / (v_sl + v_sg)) and 1, and increases with
+/// NLV/NGV and decreases with ND — consistent with more liquid accumulating in smaller,
+/// lower-gas-velocity pipes.
+fn liquid_holdup(
+    v_sl: f64,
+    v_sg: f64,
+    n_lv: f64,
+    n_gv: f64,
+    n_d: f64,
+    n_l: f64,
+) -> f64 {
+    let no_slip_holdup = v_sl / (v_sl + v_sg);
+    let group = (n_lv.powf(0.575) * (1.0 + n_l)) / ((n_gv + 1.0).powf(0.5) * n_d.max(1.0).sqrt());
+    let correction = (1.0 + group).min(1.0 / no_slip_holdup.max(1e-6));
+    (no_slip_holdup * correction).min(1.0)
+}
+
+/// Mixture density from liquid holdup: ρm = HL·ρL + (1 - HL)·ρG
+fn mixture_density(holdup: f64, props: &TwoPhaseProperties) -> f64 {
+    holdup * props.liquid_density + (1.0 - holdup) * props.gas_density
+}
+
+/// Mixture viscosity, weighted by liquid holdup (used only for the mixture Reynolds number)
+fn mixture_viscosity(holdup: f64, props: &TwoPhaseProperties) -> f64 {
+    holdup * props.liquid_viscosity + (1.0 - holdup) * props.gas_viscosity
+}
+
+/// Calculate the total two-phase pressure gradient dp/dz (Pa/m) from gravitational,
+/// frictional, and acceleration components.
+///
+/// # Arguments
+/// * `liquid_rate` - Liquid mass flow rate in kg/s
+/// * `gas_rate` - Gas mass flow rate in kg/s
+/// * `diameter` - Pipe inner diameter in meters
+/// * `angle` - Pipe inclination from horizontal in radians (π/2 = vertical upward flow)
+/// * `props` - Two-phase fluid properties
+///
+/// # Returns
+/// Pressure gradient in Pa/m (positive = pressure falls along the flow direction)
+pub fn pressure_gradient(
+    liquid_rate: f64,
+    gas_rate: f64,
+    diameter: f64,
+    angle: f64,
+    props: &TwoPhaseProperties,
+) -> f64 {
+    const GRAVITY: f64 = crate::constants::GRAVITY_EARTH;
+
+    let area = PI * diameter * diameter / 4.0;
+    let v_sl = superficial_velocity(liquid_rate, props.liquid_density, area);
+    let v_sg = superficial_velocity(gas_rate, props.gas_density, area);
+    let v_m = v_sl + v_sg;
+
+    let n_lv = liquid_velocity_number(v_sl, props);
+    let n_gv = gas_velocity_number(v_sg, props);
+    let n_d = diameter_number(diameter, props);
+    let n_l = liquid_viscosity_number(props);
+
+    let holdup = liquid_holdup(v_sl, v_sg, n_lv, n_gv, n_d, n_l);
+    let density_m = mixture_density(holdup, props);
+    let viscosity_m = mixture_viscosity(holdup, props);
+
+    // Gravitational component: ρm * g * sin(angle)
+    let gravitational = density_m * GRAVITY * angle.sin();
+
+    // Frictional component: two-phase friction factor against the mixture Reynolds number
+    let mass_flux = (liquid_rate + gas_rate) / area;
+    let reynolds_m = mass_flux * diameter / viscosity_m;
+    let friction_factor = fluid_dynamics::calculate_friction_factor(reynolds_m);
+    let frictional = friction_factor * density_m * v_m * v_m / (2.0 * diameter);
+
+    // Acceleration component: usually small outside near-sonic gas flow, kept here as a
+    // density-weighted kinetic-energy term so the gradient stays internally consistent
+    // if superficial velocities change rapidly along z (e.g. flashing near the exit).
+    let acceleration = density_m * v_m * (v_sg / (liquid_rate + gas_rate).max(1e-9));
+
+    gravitational + frictional + acceleration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn water_air_properties() -> TwoPhaseProperties {
+        TwoPhaseProperties {
+            liquid_density: 1000.0,
+            gas_density: 1.2,
+            liquid_viscosity: 1.0e-3,
+            gas_viscosity: 1.8e-5,
+            surface_tension: 0.072,
+        }
+    }
+
+    #[test]
+    fn test_pressure_gradient_positive_for_vertical_flow() {
+        let props = water_air_properties();
+        let dpdz = pressure_gradient(5.0, 0.1, 0.1, std::f64::consts::FRAC_PI_2, &props);
+        assert!(dpdz > 0.0);
+    }
+
+    #[test]
+    fn test_pressure_gradient_monotonic_in_liquid_rate() {
+        let props = water_air_properties();
+        let low = pressure_gradient(2.0, 0.1, 0.1, std::f64::consts::FRAC_PI_2, &props);
+        let high = pressure_gradient(8.0, 0.1, 0.1, std::f64::consts::FRAC_PI_2, &props);
+        assert!(high > low);
+    }
+
+    #[test]
+    fn test_pressure_gradient_monotonic_in_gas_rate() {
+        let props = water_air_properties();
+        let low = pressure_gradient(5.0, 0.05, 0.1, std::f64::consts::FRAC_PI_2, &props);
+        let high = pressure_gradient(5.0, 0.5, 0.1, std::f64::consts::FRAC_PI_2, &props);
+        assert!(high > low);
+    }
+
+    #[test]
+    fn test_horizontal_flow_has_no_gravitational_component() {
+        let props = water_air_properties();
+        let dpdz_horizontal = pressure_gradient(5.0, 0.1, 0.1, 0.0, &props);
+        let dpdz_vertical = pressure_gradient(5.0, 0.1, 0.1, std::f64::consts::FRAC_PI_2, &props);
+        assert!(dpdz_horizontal < dpdz_vertical);
+    }
+}
@@ -0,0 +1,146 @@
+/// Thin unit-checked quantity types
+///
+/// Every function elsewhere in the crate silently assumes Kelvin, kg/s, and meters, which
+/// is a common source of silent errors (e.g. passing Celsius into `sodium_density`). These
+/// wrappers tag a raw `f64` with its dimension so callers can write
+/// `Temperature::celsius(327.0)` or `Temperature::kelvin(600.0)` and `MassFlow::kg_per_s(10.0)`
+/// instead of a bare number. The raw-`f64` functions stay available for hot paths; the
+/// typed wrappers below are the recommended API.
+use crate::fluid_dynamics;
+
+/// A temperature, stored internally in Kelvin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature {
+    kelvin: f64,
+}
+
+impl Temperature {
+    pub fn kelvin(value: f64) -> Self {
+        Temperature { kelvin: value }
+    }
+
+    pub fn celsius(value: f64) -> Self {
+        Temperature { kelvin: value + 273.15 }
+    }
+
+    pub fn as_kelvin(&self) -> f64 {
+        self.kelvin
+    }
+
+    pub fn as_celsius(&self) -> f64 {
+        self.kelvin - 273.15
+    }
+}
+
+/// A mass flow rate, stored internally in kg/s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MassFlow {
+    kg_per_s: f64,
+}
+
+impl MassFlow {
+    pub fn kg_per_s(value: f64) -> Self {
+        MassFlow { kg_per_s: value }
+    }
+
+    pub fn kg_per_hr(value: f64) -> Self {
+        MassFlow { kg_per_s: value / 3600.0 }
+    }
+
+    pub fn as_kg_per_s(&self) -> f64 {
+        self.kg_per_s
+    }
+}
+
+/// A length, stored internally in meters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Length {
+    meters: f64,
+}
+
+impl Length {
+    pub fn meters(value: f64) -> Self {
+        Length { meters: value }
+    }
+
+    pub fn millimeters(value: f64) -> Self {
+        Length { meters: value / 1000.0 }
+    }
+
+    pub fn as_meters(&self) -> f64 {
+        self.meters
+    }
+}
+
+/// Unit-checked Reynolds number calculation; converts to the raw-`f64` units internally.
+pub fn calculate_reynolds_number(
+    flow_rate: MassFlow,
+    diameter: Length,
+    temperature: Temperature,
+) -> f64 {
+    fluid_dynamics::calculate_reynolds_number(
+        flow_rate.as_kg_per_s(),
+        diameter.as_meters(),
+        temperature.as_kelvin(),
+    )
+}
+
+/// Unit-checked flow velocity calculation; converts to the raw-`f64` units internally.
+pub fn calculate_velocity(flow_rate: MassFlow, diameter: Length, temperature: Temperature) -> f64 {
+    fluid_dynamics::calculate_velocity(
+        flow_rate.as_kg_per_s(),
+        diameter.as_meters(),
+        temperature.as_kelvin(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temperature_celsius_kelvin_conversion() {
+        let t = Temperature::celsius(326.85);
+        assert!((t.as_kelvin() - 600.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_temperature_kelvin_to_celsius_conversion() {
+        let t = Temperature::kelvin(600.0);
+        assert_eq!(t.as_celsius(), 600.0 - 273.15);
+    }
+
+    #[test]
+    fn test_mass_flow_kg_per_hr_conversion() {
+        let flow = MassFlow::kg_per_hr(3600.0);
+        assert!((flow.as_kg_per_s() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_length_millimeters_conversion() {
+        let length = Length::millimeters(500.0);
+        assert!((length.as_meters() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_typed_reynolds_number_matches_raw() {
+        let typed = calculate_reynolds_number(
+            MassFlow::kg_per_s(10.0),
+            Length::meters(0.5),
+            Temperature::kelvin(600.0),
+        );
+        let raw = fluid_dynamics::calculate_reynolds_number(10.0, 0.5, 600.0);
+        assert!((typed - raw).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_typed_reynolds_number_with_celsius_input() {
+        let typed = calculate_reynolds_number(
+            MassFlow::kg_per_s(10.0),
+            Length::meters(0.5),
+            Temperature::celsius(326.85),
+        );
+        let raw = fluid_dynamics::calculate_reynolds_number(10.0, 0.5, 600.0);
+        assert!((typed - raw).abs() < 1e-3);
+    }
+}